@@ -0,0 +1,93 @@
+// Copyright (C) Stefan Olivier
+// <https://stefanolivier.com>
+
+#![allow(dead_code)]
+
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub const SYNTHETIC_TRACKS: usize = 64;
+pub const SYNTHETIC_EVENTS_PER_TRACK: usize = 500;
+pub const SYNTHETIC_MARKERS: usize = 200;
+
+/// Generates a Protools EDL with `tracks` tracks of `events_per_track`
+/// back-to-back events each, one online file and clip per event, and
+/// `markers` markers
+pub fn synthetic_edl(tracks: usize, events_per_track: usize, markers: usize) -> String {
+    let mut edl = String::new();
+    let total_clips = tracks * events_per_track;
+
+    writeln!(edl, "SESSION NAME:\tSynthetic Session").unwrap();
+    writeln!(edl, "SAMPLE RATE:\t48000.000000").unwrap();
+    writeln!(edl, "BIT DEPTH:\t24-bit").unwrap();
+    writeln!(edl, "SESSION START TIMECODE:\t01:00:00:00").unwrap();
+    writeln!(edl, "TIMECODE FORMAT:\t25 Frame").unwrap();
+    writeln!(edl, "# OF AUDIO TRACKS:\t{}", tracks).unwrap();
+    writeln!(edl, "# OF AUDIO CLIPS:\t{}", total_clips).unwrap();
+    writeln!(edl, "# OF AUDIO FILES:\t{}", total_clips).unwrap();
+    writeln!(edl, "\n").unwrap();
+
+    writeln!(edl, "O N L I N E  F I L E S  I N  S E S S I O N").unwrap();
+    writeln!(edl, "Filename\tLocation").unwrap();
+    for clip in 0..total_clips {
+        writeln!(edl, "Clip_{:06}.wav\tMacintosh HD:Synthetic:Audio Files:", clip).unwrap();
+    }
+    writeln!(edl, "\n").unwrap();
+
+    writeln!(edl, "O N L I N E  C L I P S  I N  S E S S I O N").unwrap();
+    writeln!(edl, "CLIP NAME\tSource File").unwrap();
+    for clip in 0..total_clips {
+        writeln!(edl, "Clip_{:06}\tClip_{:06}.wav", clip, clip).unwrap();
+    }
+    writeln!(edl, "\n").unwrap();
+
+    writeln!(edl, "T R A C K  L I S T I N G").unwrap();
+    for track in 0..tracks {
+        writeln!(edl, "TRACK NAME:\tTrack {}", track + 1).unwrap();
+        writeln!(edl, "COMMENTS:\t").unwrap();
+        writeln!(edl, "USER DELAY:\t0 Samples").unwrap();
+        writeln!(edl, "STATE:\t").unwrap();
+        writeln!(edl, "CHANNEL\tEVENT\tCLIP NAME\tSTART TIME\tEND TIME\tDURATION\tSTATE").unwrap();
+        for event in 0..events_per_track {
+            let seconds = event * 2;
+            writeln!(
+                edl,
+                "1\t{}\tClip_{:06}\t{}\t{}\t00:00:02:00\tUnmuted",
+                event + 1,
+                track * events_per_track + event,
+                timecode_string(seconds),
+                timecode_string(seconds + 2),
+            ).unwrap();
+        }
+        writeln!(edl, "\n").unwrap();
+    }
+
+    writeln!(edl, "M A R K E R S  L I S T I N G").unwrap();
+    writeln!(edl, "#\tLOCATION\tTIME REFERENCE\tUNITS\tNAME\tCOMMENTS").unwrap();
+    for marker in 0..markers {
+        writeln!(edl, "{}\t{}\t{}\tSamples\tMarker {}\t", marker + 1, timecode_string(marker), marker * 48000, marker + 1).unwrap();
+    }
+
+    edl
+}
+
+/// Writes the default synthetic EDL to the system temporary directory and
+/// returns its path
+///
+/// The file name is `file_name` prefixed with the process id and a
+/// per-process counter, so concurrent runs never share a file; callers
+/// should remove it once parsed.
+pub fn write_synthetic_edl(file_name: &str) -> PathBuf {
+    static NEXT_FILE: AtomicUsize = AtomicUsize::new(0);
+
+    let unique_name = format!("{}_{}_{}", std::process::id(), NEXT_FILE.fetch_add(1, Ordering::Relaxed), file_name);
+    let path = std::env::temp_dir().join(unique_name);
+    std::fs::write(&path, synthetic_edl(SYNTHETIC_TRACKS, SYNTHETIC_EVENTS_PER_TRACK, SYNTHETIC_MARKERS))
+        .expect("synthetic EDL fixture should be writable to the temporary directory");
+    path
+}
+
+fn timecode_string(seconds: usize) -> String {
+    format!("{:02}:{:02}:{:02}:00", 1 + seconds / 3600, seconds / 60 % 60, seconds % 60)
+}
@@ -0,0 +1,27 @@
+// Copyright (C) Stefan Olivier
+// <https://stefanolivier.com>
+
+mod common;
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use timeline_kit::EDLProtoolsParser;
+use timeline_kit::encoding;
+
+fn parser_parse_synthetic(c: &mut Criterion) {
+    let fixture_path = common::write_synthetic_edl("timeline_kit_bench_synthetic.txt");
+    let fixture_path = fixture_path.to_str().expect("temporary directory path should be valid UTF-8");
+
+    let mut group = c.benchmark_group("EDLParser");
+    group.sample_size(20);
+    group.bench_function("parse synthetic session", |b| {
+        b.iter(|| EDLProtoolsParser::parse(black_box(fixture_path), encoding::UTF_8).expect("synthetic EDL should parse"))
+    });
+    group.finish();
+
+    std::fs::remove_file(fixture_path).expect("synthetic EDL fixture should be removable");
+}
+
+criterion_group!(benches, parser_parse_synthetic);
+criterion_main!(benches);
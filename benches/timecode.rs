@@ -0,0 +1,30 @@
+// Copyright (C) Stefan Olivier
+// <https://stefanolivier.com>
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use timeline_kit::{FrameRate, Timecode};
+
+fn timecode_from_str(c: &mut Criterion) {
+    c.bench_function("Timecode::from_str", |b| {
+        b.iter(|| Timecode::from_str(black_box("01:02:03:04"), black_box(FrameRate::Fps25)))
+    });
+}
+
+fn timecode_display(c: &mut Criterion) {
+    let timecode = Timecode::from_parts(&[1, 2, 3, 4, 0], FrameRate::Fps30(true));
+    c.bench_function("Timecode::fmt", |b| {
+        b.iter(|| black_box(&timecode).to_string())
+    });
+}
+
+fn timecode_to_frames(c: &mut Criterion) {
+    let timecode = Timecode::from_parts(&[1, 2, 3, 4, 0], FrameRate::Fps30(true));
+    c.bench_function("Timecode::to_frames", |b| {
+        b.iter(|| black_box(&timecode).to_frames())
+    });
+}
+
+criterion_group!(benches, timecode_from_str, timecode_display, timecode_to_frames);
+criterion_main!(benches);
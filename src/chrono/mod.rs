@@ -3,6 +3,28 @@
 
 #![allow(unused_braces)]
 
+mod time_range;
 mod timecode;
+mod timecode_delta;
 
-pub use timecode::Timecode;
+pub use timecode::{
+    Timecode,
+    TimecodeFormat,
+    TimecodeParseError,
+    TimecodeParseOptions,
+};
+
+pub use timecode_delta::TimecodeDelta;
+
+pub use time_range::{
+    TimeRange,
+    TIME_RANGE_TICKS_PER_SECOND,
+};
+
+// `Timecode` is built on the frame rates defined in `format`; they are
+// re-exported here rather than redefined so there is one source of truth
+pub use crate::format::{
+    BitDepth,
+    FrameRate,
+    SampleRate,
+};
@@ -0,0 +1,110 @@
+// Copyright (C) Stefan Olivier
+// <https://stefanolivier.com>
+
+use crate::chrono::Timecode;
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `TimeRange` Constants --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// The number of `TimeRange` ticks in one second; a tick is one nanosecond
+pub const TIME_RANGE_TICKS_PER_SECOND: u64 = 1_000_000_000;
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `TimeRange` Structure Definition --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// A half-open span of time `[start_ticks, end_ticks)` that does not
+/// depend on a frame rate
+///
+/// Ticks are wall-clock nanoseconds, so ranges taken from timecodes at
+/// different frame rates can be compared and combined directly.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeRange {
+    pub start_ticks: u64,
+    pub end_ticks: u64,
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `TimeRange` Implementation --
+//
+///////////////////////////////////////////////////////////////////////////
+
+impl TimeRange {
+    pub fn new(start_ticks: u64, end_ticks: u64) -> Self {
+        Self { start_ticks, end_ticks }
+    }
+
+    /// Constructs the range from `start` to `end`, each converted with
+    /// `Timecode::to_duration`
+    pub fn from_timecodes(start: Timecode, end: Timecode) -> Self {
+        Self::new(start.to_duration().as_nanos() as u64, end.to_duration().as_nanos() as u64)
+    }
+
+    /// The length of the range in ticks, or zero if `end_ticks` is not
+    /// after `start_ticks`
+    pub fn duration_ticks(&self) -> u64 {
+        self.end_ticks.saturating_sub(self.start_ticks)
+    }
+
+    /// Returns `true` if the range contains no ticks
+    pub fn is_empty(&self) -> bool {
+        self.duration_ticks() == 0
+    }
+
+    /// The range covered by both `self` and `other`, or `None` if they do
+    /// not overlap
+    ///
+    /// Ranges that only touch, where one ends at the tick the other starts,
+    /// do not overlap.
+    pub fn intersect(&self, other: &TimeRange) -> Option<TimeRange> {
+        let intersection = Self::new(self.start_ticks.max(other.start_ticks), self.end_ticks.min(other.end_ticks));
+        (!intersection.is_empty()).then_some(intersection)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `TimeRange` Unit Tests --
+//
+///////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::FrameRate;
+
+    #[test]
+    fn intersect_overlapping_ranges() {
+        let first = TimeRange::new(1_000, 5_000);
+        let second = TimeRange::new(3_000, 8_000);
+
+        assert_eq!(first.intersect(&second), Some(TimeRange::new(3_000, 5_000)));
+        assert_eq!(second.intersect(&first), first.intersect(&second));
+        assert_eq!(first.intersect(&second).map(|range| range.duration_ticks()), Some(2_000));
+        assert_eq!(first.intersect(&TimeRange::new(5_000, 6_000)), None);
+        assert_eq!(first.intersect(&TimeRange::new(2_000, 3_000)), Some(TimeRange::new(2_000, 3_000)));
+    }
+
+    #[test]
+    fn from_timecodes_across_frame_rates() {
+        let at_25 = TimeRange::from_timecodes(
+            Timecode::from_str("00:00:01:00", FrameRate::Fps25).unwrap(),
+            Timecode::from_str("00:00:02:00", FrameRate::Fps25).unwrap(),
+        );
+        let at_30 = TimeRange::from_timecodes(
+            Timecode::from_str("00:00:01:15", FrameRate::Fps30(false)).unwrap(),
+            Timecode::from_str("00:00:03:00", FrameRate::Fps30(false)).unwrap(),
+        );
+
+        assert_eq!(at_25.duration_ticks(), TIME_RANGE_TICKS_PER_SECOND);
+        assert_eq!(at_25.intersect(&at_30), Some(TimeRange::new(1_500_000_000, 2_000_000_000)));
+        assert_eq!(TimeRange::new(5, 2).duration_ticks(), 0);
+    }
+}
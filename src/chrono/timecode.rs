@@ -4,8 +4,11 @@
 #![allow(dead_code, unused_variables, unused_braces)]
 
 use std::{fmt::Display, write, ops::Rem };
-use num_traits::{Bounded, ToPrimitive};
+use num_traits::Bounded;
+use thiserror::Error;
 
+use crate::chrono::TimecodeDelta;
+use crate::edl::EDLParseField;
 use crate::format::FrameRate;
 
 ///////////////////////////////////////////////////////////////////////////
@@ -26,7 +29,7 @@ use crate::format::FrameRate;
 ///
 /// SMPTE Timecode:
 ///     1  2  3  4  5
-///     hh:mm:ss:ff:sub
+///     hh:mm:ss:ff.sub
 ///     00:00:00:00.000
 ///
 /// 1 -> Hours
@@ -37,14 +40,17 @@ use crate::format::FrameRate;
 ///
 const TC_TOTAL_GROUPS: usize = 5;
 
-/// Defines the width of the string representation of
+/// Defines the minimum width of the string representation of
 /// each group, excluding "ticks", in the `Timecode` data field
 ///
+/// Hours are not limited to this width, and are written in
+/// full once they pass 99.
+///
 /// Example:
 ///
 /// SMPTE Timecode:
 ///     2  2  2  2  -
-///     hh:mm:ss:ff:sub
+///     hh:mm:ss:ff.sub
 ///     00:00:00:00.000
 ///
 const TC_STRING_REGULAR_GROUP_SIZE: usize = 2;
@@ -56,7 +62,7 @@ const TC_STRING_REGULAR_GROUP_SIZE: usize = 2;
 ///
 /// SMPTE Timecode:
 ///     -  -  -  -  3
-///     hh:mm:ss:ff:sub
+///     hh:mm:ss:ff.sub
 ///     00:00:00:00.000
 ///
 const TC_STRING_TICKS_GROUP_SIZE: usize = 3;
@@ -80,13 +86,15 @@ const TC_STRING_DELIMITER_COLON_CHAR: char = ':';
 const TC_STRING_DELIMITER_SEMICOLON_CHAR: char = ';';
 const TC_STRING_DELIMITER_COLON: &str = ":";
 const TC_STRING_DELIMITER_SEMICOLON: &str = ";";
-const TC_TICK_RESOLUTION: usize = 100;
+const TC_STRING_DELIMITER_TICKS_CHAR: char = '.';
+const TC_STRING_DELIMITER_TICKS: &str = ".";
+pub(super) const TC_TICK_RESOLUTION: usize = 100;
 const TC_SCALAR_HOURS_INDEX: usize = 0;
 const TC_SCALAR_MINUTES_INDEX: usize = 1;
 const TC_SCALAR_SECONDS_INDEX: usize = 2;
 const TC_SCALAR_FRAMES_INDEX: usize = 3;
 const TC_SCALAR_TICKS_INDEX: usize = 4;
-const TC_DELIMITER_DROPFRAME_INDEX: usize = ((TC_TOTAL_GROUPS - 2) * (TC_STRING_REGULAR_GROUP_SIZE + 1)) - 1;
+const TC_DELIMITER_DROPFRAME_POSITION: usize = TC_SCALAR_SECONDS_INDEX;
 const TC_FLAGS_DEFAULT: TimecodeFlag = 0;
 const TC_FLAGS_DROPFRAME: TimecodeFlag = 1 << 0;
 const TC_SCALAR_ORDER_TABLE: [usize; TC_TOTAL_GROUPS] = [
@@ -97,25 +105,11 @@ const TC_SCALAR_ORDER_TABLE: [usize; TC_TOTAL_GROUPS] = [
     TC_SCALAR_TICKS_INDEX,
 ];
 
-const TC_CONFIG_HOURS_INDEX: usize = TC_SCALAR_HOURS_INDEX;
-const TC_CONFIG_MINUTES_INDEX: usize = TC_SCALAR_MINUTES_INDEX;
-const TC_CONFIG_SECONDS_INDEX: usize = TC_SCALAR_SECONDS_INDEX;
-const TC_CONFIG_FRAMES_INDEX: usize = TC_SCALAR_FRAMES_INDEX;
-const TC_CONFIG_TICKS_INDEX: usize = TC_SCALAR_TICKS_INDEX;
-const TC_CONFIG_GROUP_TICKS_FACTOR_INDEX: usize = 0;
-const TC_CONFIG_GROUP_APPLY_FPS_INDEX: usize = 1;
-enum TernaryPredicate {
-    True,
-    False,
-    Other,
-}
-static TC_CONFIG_TABLE: [(usize, TernaryPredicate); TC_TOTAL_GROUPS] = [
-    (60 * 60, TernaryPredicate::True),
-    (60, TernaryPredicate::True),
-    (1, TernaryPredicate::True),
-    (1, TernaryPredicate::False),
-    (1, TernaryPredicate::Other),
-];
+const TC_SECONDS_PER_MINUTE: u64 = 60;
+const TC_SECONDS_PER_HOUR: u64 = 60 * 60;
+const TC_MINUTES_PER_DROPFRAME_CYCLE: u64 = 10;
+const TC_HOURS_PER_DAY: u64 = 24;
+const TC_NANOS_PER_SECOND: u128 = 1_000_000_000;
 
 ///////////////////////////////////////////////////////////////////////////
 //
@@ -123,12 +117,84 @@ static TC_CONFIG_TABLE: [(usize, TernaryPredicate); TC_TOTAL_GROUPS] = [
 //
 ///////////////////////////////////////////////////////////////////////////
 
-type TimecodeScalar = u8;
+type TimecodeScalar = u32;
 type TimecodeData = [TimecodeScalar; TC_TOTAL_GROUPS];
 type TimecodeFlag = u8;
 type TimecodeFrameRate = FrameRate;
 type TimecodeU64 = u64;
 
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `Timecode` Error Types --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// Errors that can be produced while parsing a `Timecode` from a string
+#[derive(Debug, Error, PartialEq, Eq, Clone, Copy)]
+pub enum TimecodeParseError {
+    #[error("timecode has an invalid number of groups")]
+    InvalidGroupCount,
+    #[error("timecode group is not a valid number")]
+    InvalidGroup,
+    #[error("timecode has a misplaced drop-frame delimiter")]
+    InvalidDelimiter,
+    #[error("timecode group is out of range for the frame rate")]
+    GroupOutOfRange,
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `Timecode` Parsing Options --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// Options that control how `Timecode::from_str_with_options` treats its
+/// input
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct TimecodeParseOptions {
+    /// Carry groups that overflow their range into the next group, e.g.
+    /// `00:00:00:25` at 25fps becomes `00:00:01:00`, instead of rejecting
+    /// the timecode
+    pub normalize_on_parse: bool,
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `TimecodeFormat` Declaration --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// The layout `Timecode::format_with` writes a timecode in
+///
+/// The default layout is the one used by `Display`, `hh:mm:ss:ff` or
+/// `hh:mm:ss;ff` for drop-frame.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TimecodeFormat {
+    /// Written between groups, e.g. `""` for `hhmmssff`
+    pub delimiter: &'static str,
+    /// Written between the seconds and frames groups of a drop-frame
+    /// timecode instead of `delimiter`
+    pub dropframe_delimiter: &'static str,
+    /// Write the frames group, or stop at the seconds for `hh:mm:ss`
+    pub include_frames: bool,
+    /// Write the ticks group as `.ttt` after the frames when it is nonzero
+    pub include_ticks: bool,
+    /// Write the total frame count, e.g. `90000`, instead of the groups
+    pub frames_only: bool,
+}
+
+impl Default for TimecodeFormat {
+    fn default() -> Self {
+        Self {
+            delimiter: TC_STRING_DELIMITER_COLON,
+            dropframe_delimiter: TC_STRING_DELIMITER_SEMICOLON,
+            include_frames: true,
+            include_ticks: false,
+            frames_only: false,
+        }
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////
 //
 //  -- @SECTION `Timecode` Structure Definition --
@@ -136,7 +202,7 @@ type TimecodeU64 = u64;
 ///////////////////////////////////////////////////////////////////////////
 
 /// The primary structure for encapsulating timecode scalar data
-#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
 pub struct Timecode {
     data: TimecodeData,
     fps: TimecodeFrameRate,
@@ -157,57 +223,93 @@ impl Timecode {
             ..Timecode::default()
         };
         
-        use FrameRate::*;
-        match fps {
-            Fps24(true) | Fps30(true) | Fps60(true) => {
-                timecode.set_flag(TC_FLAGS_DROPFRAME);
-            },
-            _ => {},
+        if fps.is_drop_frame() {
+            timecode.set_flag(TC_FLAGS_DROPFRAME);
         }
 
         timecode
     }
 
+    /// Constructs a `Timecode` from `[hours, minutes, seconds, frames, ticks]`
+    /// without checking the groups; use `Timecode::try_from_parts` for
+    /// groups that have not been validated
     pub fn from_parts(groups: &[TimecodeScalar; TC_TOTAL_GROUPS], fps: FrameRate) -> Self {
-        // TODO: Check bounds of groups
-        // TODO: Check flags based on bounds check of groups
-
         let mut timecode = Self {
-            data: groups.clone(),
+            data: *groups,
             fps,
             ..Timecode::default()
         };
         
-        use FrameRate::*;
-        match fps {
-            Fps24(true) | Fps30(true) | Fps60(true) => {
-                timecode.set_flag(TC_FLAGS_DROPFRAME);
-            },
-            _ => {},
+        if fps.is_drop_frame() {
+            timecode.set_flag(TC_FLAGS_DROPFRAME);
         }
 
         timecode
     }
 
-    pub fn from_str(tc_string: &str, fps: FrameRate) -> Result<Self, ()> { // TODO: ErrorType for timecodes
-        // TODO: ErrorType for timecodes
-        let is_drop_frame = tc_string.find(TC_STRING_DELIMITER_SEMICOLON)
-            .map_or(Ok(false), |v| {
-                if v == TC_DELIMITER_DROPFRAME_INDEX { Ok(true) } else { Err(()) }
-            })?;
+    /// Checked variant of `Timecode::from_parts`, rejecting groups that are
+    /// out of range for the frame rate in the same way as
+    /// `Timecode::from_str`
+    ///
+    /// Minutes and seconds must be below 60, frames below the nominal frame
+    /// rate and ticks below `TC_TICK_RESOLUTION`, and at drop-frame rates
+    /// the groups must not name a skipped frame label.
+    pub fn try_from_parts(groups: &[TimecodeScalar; TC_TOTAL_GROUPS], fps: FrameRate) -> Result<Self, TimecodeParseError> {
+        let timecode = Self::from_parts(groups, fps);
+        if !timecode.groups_in_range() {
+            return Err(TimecodeParseError::GroupOutOfRange);
+        }
+
+        Ok(timecode)
+    }
+
+    /// Parses a `Timecode` from a string, rejecting groups that are out of
+    /// range for the frame rate (e.g. frame 25 at 25fps)
+    pub fn from_str(tc_string: &str, fps: FrameRate) -> Result<Self, TimecodeParseError> {
+        Self::from_str_with_options(tc_string, fps, TimecodeParseOptions::default())
+    }
 
-        let parts = tc_string.split([TC_STRING_DELIMITER_COLON_CHAR, TC_STRING_DELIMITER_SEMICOLON_CHAR])
-            .into_iter()
-            .map(|c| c.parse::<TimecodeScalar>().expect("timecode string parts must be a valid TimecodeScalar"));
+    /// Parses a `Timecode` from a string with the given parsing options
+    pub fn from_str_with_options(tc_string: &str, fps: FrameRate, options: TimecodeParseOptions) -> Result<Self, TimecodeParseError> {
+        let (groups_string, ticks_string) = match tc_string.split_once(TC_STRING_DELIMITER_TICKS_CHAR) {
+            Some((groups_string, ticks_string)) => (groups_string, Some(ticks_string)),
+            None => (tc_string, None),
+        };
 
-        let total_parts = parts.clone().count();
+        // A drop-frame `;` is only valid between the seconds and frames
+        // groups, wherever that falls in the string
+        let mut is_drop_frame = false;
+        let delimiters = groups_string.chars()
+            .filter(|&c| c == TC_STRING_DELIMITER_COLON_CHAR || c == TC_STRING_DELIMITER_SEMICOLON_CHAR);
+
+        for (position, delimiter) in delimiters.enumerate() {
+            if delimiter == TC_STRING_DELIMITER_SEMICOLON_CHAR {
+                if position != TC_DELIMITER_DROPFRAME_POSITION {
+                    return Err(TimecodeParseError::InvalidDelimiter);
+                }
+
+                is_drop_frame = true;
+            }
+        }
+
+        let parts = groups_string.split([TC_STRING_DELIMITER_COLON_CHAR, TC_STRING_DELIMITER_SEMICOLON_CHAR])
+            .chain(ticks_string)
+            .map(|c| c.parse::<TimecodeScalar>().map_err(|_| TimecodeParseError::InvalidGroup))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let total_parts = parts.len();
+
+        // Ticks are only accepted after a full set of regular groups, either
+        // as `hh:mm:ss:ff.ttt` or as a fifth `:` delimited group
+        if ticks_string.is_some() && total_parts != TC_TOTAL_GROUPS {
+            return Err(TimecodeParseError::InvalidGroupCount);
+        }
 
         if total_parts != TC_TOTAL_GROUPS
            && total_parts != TC_REGULAR_TOTAL_GROUPS
            && total_parts != TC_TOTAL_GROUPS_MINSEC
         {
-            // TODO: Change to more meaningful error
-            return Err(());
+            return Err(TimecodeParseError::InvalidGroupCount);
         }
 
         let mut timecode = Self {
@@ -215,7 +317,7 @@ impl Timecode {
             ..Timecode::default()
         };
 
-        for (i, scalar) in parts.enumerate() {
+        for (i, scalar) in parts.into_iter().enumerate() {
             timecode.data[i] = scalar;
         }
 
@@ -223,347 +325,435 @@ impl Timecode {
             timecode.set_flag(TC_FLAGS_DROPFRAME);
         }
 
-        Ok(timecode)
-    }
-}
-
-///////////////////////////////////////////////////////////////////////////
-//
-//  -- @SECTION `Timecode` Public Methods --
-//
-///////////////////////////////////////////////////////////////////////////
-
-impl Timecode {
-    pub fn hours<T: num_traits::PrimInt>(&self) -> T {
-        return T::from(self.data[TC_SCALAR_HOURS_INDEX]).unwrap();
-    }
-
-    pub fn minutes<T: num_traits::PrimInt>(&self) -> T {
-        return T::from(self.data[TC_SCALAR_MINUTES_INDEX]).unwrap();
-    }
-
-    pub fn seconds<T: num_traits::PrimInt>(&self) -> T {
-        return T::from(self.data[TC_SCALAR_SECONDS_INDEX]).unwrap();
-    }
-
-    pub fn frames<T: num_traits::PrimInt>(&self) -> T {
-        return T::from(self.data[TC_SCALAR_FRAMES_INDEX]).unwrap();
-    }
-
-    pub fn ticks<T: num_traits::PrimInt>(&self) -> T {
-        return T::from(self.data[TC_SCALAR_TICKS_INDEX]).unwrap();
-    }
-
-    pub fn frame_rate(&self) -> TimecodeFrameRate {
-        self.fps
-    }
+        if !timecode.groups_in_range() {
+            if !options.normalize_on_parse {
+                return Err(TimecodeParseError::GroupOutOfRange);
+            }
 
-    pub fn to_ticks(&self) -> usize {
-        let mut ticks: usize = 0;
-        for (scalar, i) in self.data.iter().zip(TC_SCALAR_ORDER_TABLE) {
-            match TC_CONFIG_TABLE[i].1 {
-                TernaryPredicate::True => ticks += *scalar as usize * TC_CONFIG_TABLE[i].0 * self.fps.as_float().to_usize().unwrap() * TC_TICK_RESOLUTION,
-                TernaryPredicate::False => ticks += *scalar as usize * TC_CONFIG_TABLE[i].0 * TC_TICK_RESOLUTION,
-                TernaryPredicate::Other => ticks += *scalar as usize,
+            if timecode.is_dropped_label() {
+                timecode.data[TC_SCALAR_FRAMES_INDEX] = timecode.dropped_frames_per_minute() as TimecodeScalar;
             }
+
+            timecode.set_ticks(timecode.to_ticks());
         }
 
-        ticks
+        Ok(timecode)
     }
 
-    pub fn set_frame_rate(&mut self, fps: FrameRate) {
-        self.fps = fps;
+    /// Constructs a `Timecode` from a total frame count at the specified
+    /// frame rate
+    pub fn from_frames(frames: u64, fps: FrameRate) -> Self {
+        Self::from_ticks(frames as usize * TC_TICK_RESOLUTION, fps)
     }
 
-    pub fn check_flag(&self, flag: TimecodeFlag) -> bool {
-        self.flags & flag == flag
+    /// Constructs a `Timecode` from a total sub-frame tick count at the
+    /// specified frame rate
+    pub fn from_ticks(ticks: usize, fps: FrameRate) -> Self {
+        let mut timecode = Self::with_fps(fps);
+        timecode.set_ticks(ticks);
+        timecode
     }
 
-    pub fn set_flag(&mut self, flag: TimecodeFlag) {
-        self.flags |= flag;
-    }
+    /// Constructs a `Timecode` from a wall-clock duration at the specified
+    /// frame rate, rounded to the nearest tick
+    ///
+    /// The duration is converted with the exact rational frame rate, so
+    /// fractional rates such as 29.97 are not approximated.
+    pub fn from_duration(duration: std::time::Duration, fps: FrameRate) -> Self {
+        let (numerator, denominator) = fps.as_rational();
+        let scaled_nanos = duration.as_nanos() * numerator as u128 * TC_TICK_RESOLUTION as u128;
+        let divisor = denominator as u128 * TC_NANOS_PER_SECOND;
 
-    pub fn reset_flag(&mut self, flag: TimecodeFlag) {
-        self.flags &= !flag;
+        Self::from_ticks(((scaled_nanos + divisor / 2) / divisor) as usize, fps)
     }
 }
 
 ///////////////////////////////////////////////////////////////////////////
 //
-//  -- @SECTION `Timecode` Trait Implementations --
+//  -- @SECTION `Timecode` Public Methods --
 //
 ///////////////////////////////////////////////////////////////////////////
 
-impl Default for Timecode {
-    fn default() -> Self {
-        Self {
-            data: [TimecodeScalar::default(); TC_TOTAL_GROUPS],
-            fps: FrameRate::default(),
-            flags: 0,
-        }
+impl Timecode {
+    /// The hours group, in any integer type that holds a `u32` losslessly
+    ///
+    /// Hours are not limited to a day, so `300:00:00:00` parses, and a
+    /// narrower type such as `u8` is rejected at compile time rather than
+    /// truncating or panicking.
+    pub fn hours<T: From<TimecodeScalar>>(&self) -> T {
+        T::from(self.data[TC_SCALAR_HOURS_INDEX])
     }
-}
 
-impl Display for Timecode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // TODO: Handle display of drop-frame delimiter
-        //
-        // drop-frame timecode skips frame numbers 0 and 1 of the first second of every minute,
-        // except when the number of minutes is divisible by ten. This causes timecode to skip
-        // 18 frames each ten minutes (18,000 frames @ 30 frame/s) and almost perfectly compensates
-        // for the difference in rate (but still accumulates 1 frame every 9 hours 15 minutes).
-        // <source: https://en.wikipedia.org/wiki/SMPTE_timecode#Drop-frame_timecode>
-
-        // TODO: Handle display of ticks/sub-frames
-
-        let mut tc_string = String::with_capacity(TC_STRING_REGULAR_LENGTH);
-        for (i, &scalar) in self.data.iter().take(TC_TOTAL_GROUPS - 1).enumerate() {
-            let delimiter = if i < TC_TOTAL_GROUPS - 2 {
-                if i == TC_SCALAR_SECONDS_INDEX && self.check_flag(TC_FLAGS_DROPFRAME) {
-                    TC_STRING_DELIMITER_SEMICOLON
-                } else {
-                    TC_STRING_DELIMITER_COLON
-                }
-            } else {
-                ""
-            };
-            tc_string += format!("{:0>2}{}", scalar, delimiter).as_str();
-        }
-
-        write!(f, "{}", tc_string)
+    pub fn minutes<T: From<TimecodeScalar>>(&self) -> T {
+        T::from(self.data[TC_SCALAR_MINUTES_INDEX])
     }
-}
 
-impl num_traits::PrimInt for Timecode {
-    fn signed_shl(self, n: u32) -> Self {
-        todo!()
+    pub fn seconds<T: From<TimecodeScalar>>(&self) -> T {
+        T::from(self.data[TC_SCALAR_SECONDS_INDEX])
     }
 
-    fn pow(self, exp: u32) -> Self {
-        todo!()
+    pub fn frames<T: From<TimecodeScalar>>(&self) -> T {
+        T::from(self.data[TC_SCALAR_FRAMES_INDEX])
     }
 
-    fn to_be(self) -> Self {
-        todo!()
+    pub fn ticks<T: From<TimecodeScalar>>(&self) -> T {
+        T::from(self.data[TC_SCALAR_TICKS_INDEX])
     }
 
-    fn to_le(self) -> Self {
-        todo!()
+    pub fn frame_rate(&self) -> TimecodeFrameRate {
+        self.fps
     }
 
-    fn count_ones(self) -> u32 {
-        todo!()
+    /// Total number of sub-frame ticks represented by this `Timecode`,
+    /// at its own frame rate
+    pub fn to_ticks(&self) -> usize {
+        self.to_frames() as usize * TC_TICK_RESOLUTION + self.data[TC_SCALAR_TICKS_INDEX] as usize
     }
 
-    fn signed_shr(self, n: u32) -> Self {
-        todo!()
+    /// Compares the time this `Timecode` represents with `other`, which
+    /// may be at a different frame rate
+    ///
+    /// Unlike `Ord`, which compares the groups, `other` is first converted
+    /// to ticks at this `Timecode`'s frame rate, so `00:00:01:00` at 25fps
+    /// and `00:00:01:00` at 30fps are equal.
+    pub fn cmp_time(&self, other: &Timecode) -> std::cmp::Ordering {
+        self.to_ticks().cmp(&other.ticks_at(self.fps))
     }
 
-    fn swap_bytes(self) -> Self {
-        todo!()
+    /// The later of `self` and `other` by `Timecode::cmp_time`, returning
+    /// `other` when they are equal, as `std::cmp::max` does
+    pub fn max(self, other: Timecode) -> Timecode {
+        if self.cmp_time(&other).is_gt() { self } else { other }
     }
 
-    fn count_zeros(self) -> u32 {
-        todo!()
+    /// The earlier of `self` and `other` by `Timecode::cmp_time`, returning
+    /// `self` when they are equal, as `std::cmp::min` does
+    pub fn min(self, other: Timecode) -> Timecode {
+        if other.cmp_time(&self).is_lt() { other } else { self }
     }
 
-    fn rotate_left(self, n: u32) -> Self {
-        todo!()
+    /// Returns `true` if this `Timecode` falls within the half-open range
+    /// `[start, end)`, by `Timecode::cmp_time`
+    pub fn is_between(&self, start: Timecode, end: Timecode) -> bool {
+        self.cmp_time(&start).is_ge() && self.cmp_time(&end).is_lt()
     }
 
-    fn leading_ones(self) -> u32 {
-        todo!()
+    /// Returns `true` if this `Timecode` falls within the closed range
+    /// `[start, end]`, by `Timecode::cmp_time`
+    pub fn is_between_inclusive(&self, start: Timecode, end: Timecode) -> bool {
+        self.cmp_time(&start).is_ge() && self.cmp_time(&end).is_le()
     }
 
-    fn rotate_right(self, n: u32) -> Self {
-        todo!()
-    }
+    /// The wall-clock time represented by this `Timecode`, rounded to the
+    /// nearest nanosecond
+    ///
+    /// The ticks are converted with the exact rational frame rate, e.g. one
+    /// hour of non-drop-frame 29.97 timecode is 3603.6 seconds.
+    pub fn to_duration(&self) -> std::time::Duration {
+        let (numerator, denominator) = self.fps.as_rational();
+        let scaled_ticks = self.to_ticks() as u128 * denominator as u128 * TC_NANOS_PER_SECOND;
+        let divisor = numerator as u128 * TC_TICK_RESOLUTION as u128;
 
-    fn unsigned_shl(self, n: u32) -> Self {
-        todo!()
+        std::time::Duration::from_nanos(((scaled_ticks + divisor / 2) / divisor) as u64)
     }
 
-    fn unsigned_shr(self, n: u32) -> Self {
-       todo!()
-    }
+    /// Writes this `Timecode` in the given layout
+    ///
+    /// Groups are padded to at least two digits, as with `Display`.
+    pub fn format_with(&self, format: TimecodeFormat) -> String {
+        let ticks = self.data[TC_SCALAR_TICKS_INDEX];
+        let ticks_string = if format.include_frames && format.include_ticks && ticks > 0 {
+            format!("{}{:0>width$}", TC_STRING_DELIMITER_TICKS, ticks, width = TC_STRING_TICKS_GROUP_SIZE)
+        } else {
+            String::new()
+        };
 
-    fn reverse_bits(self) -> Self {
-        todo!()
-    }
+        if format.frames_only {
+            return format!("{}{}", self.to_frames(), ticks_string);
+        }
 
-    fn leading_zeros(self) -> u32 {
-        todo!()
-    }
+        // Drop-frame timecode skips frame numbers 0 and 1 of the first second of every minute,
+        // except when the number of minutes is divisible by ten. This causes timecode to skip
+        // 18 frames each ten minutes (18,000 frames @ 30 frame/s) and almost perfectly compensates
+        // for the difference in rate (but still accumulates 1 frame every 9 hours 15 minutes).
+        // <source: https://en.wikipedia.org/wiki/SMPTE_timecode#Drop-frame_timecode>
+        let total_groups = if format.include_frames { TC_REGULAR_TOTAL_GROUPS } else { TC_REGULAR_TOTAL_GROUPS - 1 };
+        let mut tc_string = String::with_capacity(TC_STRING_REGULAR_LENGTH);
+        for (i, &scalar) in self.data.iter().take(total_groups).enumerate() {
+            if i == TC_SCALAR_FRAMES_INDEX && self.check_flag(TC_FLAGS_DROPFRAME) {
+                tc_string += format.dropframe_delimiter;
+            } else if i > 0 {
+                tc_string += format.delimiter;
+            }
 
-    fn trailing_ones(self) -> u32 {
-        todo!()
-    }
+            tc_string += format!("{:0>width$}", scalar, width = TC_STRING_REGULAR_GROUP_SIZE).as_str();
+        }
 
-    fn trailing_zeros(self) -> u32 {
-        todo!()
+        tc_string + ticks_string.as_str()
+    }
+
+    /// The signed distance from `rhs` to this `Timecode`, at this
+    /// `Timecode`'s frame rate
+    ///
+    /// Unlike `self - rhs`, which saturates at zero, the result is negative
+    /// when `rhs` is later than this `Timecode`.
+    pub fn signed_sub(&self, rhs: Timecode) -> TimecodeDelta {
+        TimecodeDelta::from_ticks(self.to_ticks() as i64 - rhs.ticks_at(self.fps) as i64, self.fps)
+    }
+
+    /// Total number of frames represented by this `Timecode`, at its own
+    /// frame rate
+    ///
+    /// For drop-frame timecodes the frame labels that are skipped at the
+    /// start of each minute (except every tenth minute) are not counted.
+    pub fn to_frames(&self) -> u64 {
+        let fps = self.nominal_fps();
+        let hours = self.data[TC_SCALAR_HOURS_INDEX] as u64;
+        let minutes = self.data[TC_SCALAR_MINUTES_INDEX] as u64;
+        let seconds = self.data[TC_SCALAR_SECONDS_INDEX] as u64;
+        let frames = self.data[TC_SCALAR_FRAMES_INDEX] as u64;
+
+        let total_minutes = hours * 60 + minutes;
+        let total_frames = (hours * TC_SECONDS_PER_HOUR + minutes * TC_SECONDS_PER_MINUTE + seconds) * fps + frames;
+        let dropped_frames = self.dropped_frames_per_minute() * (total_minutes - total_minutes / TC_MINUTES_PER_DROPFRAME_CYCLE);
+
+        total_frames.saturating_sub(dropped_frames)
+    }
+
+    /// Returns a copy of this `Timecode` moved later by `frames` frames
+    pub fn add_frames(&self, frames: u64) -> Self {
+        Self::from_ticks(self.to_ticks() + frames as usize * TC_TICK_RESOLUTION, self.fps)
+    }
+
+    /// Returns a copy of this `Timecode` moved earlier by `frames` frames,
+    /// saturating at zero
+    pub fn sub_frames(&self, frames: u64) -> Self {
+        Self::from_ticks(self.to_ticks().saturating_sub(frames as usize * TC_TICK_RESOLUTION), self.fps)
+    }
+
+    /// Returns a copy of this `Timecode` moved by a signed number of frames,
+    /// clamped between zero and the last frame of the day (e.g.
+    /// `23:59:59:24` at 25fps) instead of rolling over
+    ///
+    /// Frames are stepped at the nominal integer rate, so drop-frame
+    /// timecodes step over the skipped frame labels.
+    pub fn saturating_add_frames(&self, frames: i64) -> Self {
+        let last_frame = self.fps.nominal() as TimecodeScalar - 1;
+        let last_hour = TC_HOURS_PER_DAY as TimecodeScalar - 1;
+        let last_frame_of_day = Self::from_parts(&[last_hour, 59, 59, last_frame, 0], self.fps);
+
+        let ticks = (self.to_ticks() as i64).saturating_add(frames.saturating_mul(TC_TICK_RESOLUTION as i64));
+        Self::from_ticks(ticks.clamp(0, last_frame_of_day.to_ticks() as i64) as usize, self.fps)
+    }
+
+    /// Returns `start` if this `Timecode` is before it, `end` if it is after
+    /// it, or else a copy of this `Timecode`
+    ///
+    /// Timecodes are compared by their tick counts at this `Timecode`'s
+    /// frame rate, so `start` and `end` may be at other frame rates, and
+    /// the result is always at this `Timecode`'s frame rate. If `start` is
+    /// after `end`, `start` wins.
+    pub fn clamp_to_range(&self, start: Timecode, end: Timecode) -> Timecode {
+        let ticks = self.to_ticks();
+        let start_ticks = start.ticks_at(self.fps);
+        let end_ticks = end.ticks_at(self.fps);
+
+        if ticks < start_ticks { Self::from_ticks(start_ticks, self.fps) }
+        else if ticks > end_ticks { Self::from_ticks(end_ticks, self.fps) }
+        else { *self }
+    }
+
+    /// The frame rate implied by this `Timecode`'s delimiters, e.g. a
+    /// `;` delimited timecode at 30fps is inferred to be 29.97 drop-frame
+    ///
+    /// Frame rates without a drop-frame variant are returned unchanged.
+    pub fn inferred_frame_rate(&self) -> FrameRate {
+        let is_drop_frame = self.check_flag(TC_FLAGS_DROPFRAME);
+        match self.fps {
+            FrameRate::Fps24(_) => FrameRate::Fps24(is_drop_frame),
+            FrameRate::Fps30(_) => FrameRate::Fps30(is_drop_frame),
+            FrameRate::Fps60(_) => FrameRate::Fps60(is_drop_frame),
+            fps => fps,
+        }
     }
 
-    fn from_be(x: Self) -> Self {
-        todo!()
+    /// Returns `true` if this `Timecode` is counted with drop-frame labels
+    /// and displayed with a `;` delimiter
+    pub fn is_drop_frame(&self) -> bool {
+        self.check_flag(TC_FLAGS_DROPFRAME)
     }
 
-    fn from_le(x: Self) -> Self {
-        todo!()
+    /// Changes the frame rate this `Timecode` is counted at, setting or
+    /// clearing its drop-frame flag to match `fps`
+    ///
+    /// The groups are kept as they are rather than converted to the new
+    /// rate.
+    pub fn set_frame_rate(&mut self, fps: FrameRate) {
+        self.fps = fps;
+        if fps.is_drop_frame() {
+            self.set_flag(TC_FLAGS_DROPFRAME);
+        } else {
+            self.reset_flag(TC_FLAGS_DROPFRAME);
+        }
     }
-}
 
-impl num_traits::Saturating for Timecode {
-    fn saturating_add(self, v: Self) -> Self {
-        todo!()
+    pub fn check_flag(&self, flag: TimecodeFlag) -> bool {
+        self.flags & flag == flag
     }
 
-    fn saturating_sub(self, v: Self) -> Self {
-        todo!()
+    pub fn set_flag(&mut self, flag: TimecodeFlag) {
+        self.flags |= flag;
     }
-}
 
-impl num_traits::Num for Timecode {
-    // TODO: Change this to a valid error type
-    type FromStrRadixErr = String;
-    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-        todo!()
+    pub fn reset_flag(&mut self, flag: TimecodeFlag) {
+        self.flags &= !flag;
     }
 }
 
-impl num_traits::Zero for Timecode {
-    fn zero() -> Self {
-        todo!()
-    }
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `Timecode` Private Methods --
+//
+///////////////////////////////////////////////////////////////////////////
 
-    fn is_zero(&self) -> bool {
-        todo!()
-    }
+impl Timecode {
+    /// Replaces the groups of this `Timecode` with the labels for a total
+    /// tick count, at its own frame rate and drop-frame setting
+    fn set_ticks(&mut self, ticks: usize) {
+        let nominal_fps = self.nominal_fps();
+        let dropped_frames = self.dropped_frames_per_minute();
+        let mut frames = (ticks / TC_TICK_RESOLUTION) as u64;
+
+        if dropped_frames > 0 {
+            let frames_per_minute = nominal_fps * TC_SECONDS_PER_MINUTE - dropped_frames;
+            let frames_per_cycle = nominal_fps * TC_SECONDS_PER_MINUTE * TC_MINUTES_PER_DROPFRAME_CYCLE
+                                   - dropped_frames * (TC_MINUTES_PER_DROPFRAME_CYCLE - 1);
+            let cycles = frames / frames_per_cycle;
+            let remainder = frames % frames_per_cycle;
+
+            frames += dropped_frames * (TC_MINUTES_PER_DROPFRAME_CYCLE - 1) * cycles;
+            if remainder > dropped_frames {
+                frames += dropped_frames * ((remainder - dropped_frames) / frames_per_minute);
+            }
+        }
 
-    fn set_zero(&mut self) {
-        todo!()
+        let hours = frames / (nominal_fps * TC_SECONDS_PER_HOUR);
+        self.data = [
+            hours.min(TimecodeScalar::MAX as u64) as TimecodeScalar,
+            (frames / (nominal_fps * TC_SECONDS_PER_MINUTE) % 60) as TimecodeScalar,
+            (frames / nominal_fps % 60) as TimecodeScalar,
+            (frames % nominal_fps) as TimecodeScalar,
+            (ticks % TC_TICK_RESOLUTION) as TimecodeScalar,
+        ];
     }
-}
 
-impl num_traits::One for Timecode {
-    fn one() -> Self {
-        todo!()
+    /// Returns `true` if every group is within the range allowed at this
+    /// `Timecode`'s frame rate, and the groups are not a frame label that
+    /// drop-frame counting skips
+    fn groups_in_range(&self) -> bool {
+        (self.data[TC_SCALAR_MINUTES_INDEX] as u64) < TC_SECONDS_PER_MINUTE
+        && (self.data[TC_SCALAR_SECONDS_INDEX] as u64) < TC_SECONDS_PER_MINUTE
+        && (self.data[TC_SCALAR_FRAMES_INDEX] as u64) < self.nominal_fps()
+        && (self.data[TC_SCALAR_TICKS_INDEX] as usize) < TC_TICK_RESOLUTION
+        && !self.is_dropped_label()
     }
 
-    fn is_one(&self) -> bool
-    where
-        Self: PartialEq, 
-    {
-        todo!()
+    /// Returns `true` if the groups name one of the frames that drop-frame
+    /// counting skips, i.e. frames 0 and 1 (0 to 3 at 59.94) of the first
+    /// second of every minute that is not a multiple of ten
+    fn is_dropped_label(&self) -> bool {
+        let minutes = self.data[TC_SCALAR_MINUTES_INDEX] as u64;
+        self.data[TC_SCALAR_SECONDS_INDEX] == 0
+        && !minutes.is_multiple_of(TC_MINUTES_PER_DROPFRAME_CYCLE)
+        && (self.data[TC_SCALAR_FRAMES_INDEX] as u64) < self.dropped_frames_per_minute()
     }
 
-    fn set_one(&mut self) {
-        todo!()
+    fn nominal_fps(&self) -> u64 {
+        self.fps.nominal() as u64
     }
-}
 
-impl num_traits::SaturatingAdd for Timecode {
-    fn saturating_add(&self, v: &Self) -> Self {
-        todo!()
-    }
-}
+    fn dropped_frames_per_minute(&self) -> u64 {
+        if !self.check_flag(TC_FLAGS_DROPFRAME) {
+            return 0;
+        }
 
-impl num_traits::SaturatingSub for Timecode {
-    fn saturating_sub(&self, v: &Self) -> Self {
-        todo!()
+        match self.nominal_fps() {
+            30 => 2,
+            60 => 4,
+            _ => 0,
+        }
     }
-}
 
-impl num_traits::SaturatingMul for Timecode {
-    fn saturating_mul(&self, v: &Self) -> Self {
-        todo!()
+    /// The largest tick count a `Timecode` can hold at `fps`, which is
+    /// the last tick of the largest hour
+    fn max_ticks(fps: FrameRate) -> usize {
+        let last_frame = fps.nominal() as TimecodeScalar - 1;
+        let last_tick = TC_TICK_RESOLUTION as TimecodeScalar - 1;
+        Self::from_parts(&[TimecodeScalar::MAX, 59, 59, last_frame, last_tick], fps).to_ticks()
     }
-}
 
-impl num_traits::CheckedAdd for Timecode {
-    fn checked_add(&self, v: &Self) -> Option<Self> {
-        todo!()
-    }
-}
+    /// Converts this `Timecode`'s ticks to the equivalent tick count at
+    /// another frame rate
+    pub(crate) fn ticks_at(&self, fps: FrameRate) -> usize {
+        if self.fps == fps {
+            return self.to_ticks();
+        }
 
-impl num_traits::CheckedSub for Timecode {
-    fn checked_sub(&self, v: &Self) -> Option<Self> {
-        todo!()
-    }
-}
+        // ticks * (to_numerator / to_denominator) / (from_numerator / from_denominator),
+        // rounded to the nearest tick, in exact integer arithmetic
+        let (from_numerator, from_denominator) = self.fps.as_rational();
+        let (to_numerator, to_denominator) = fps.as_rational();
+        let scaled_ticks = self.to_ticks() as u128 * from_denominator as u128 * to_numerator as u128;
+        let divisor = from_numerator as u128 * to_denominator as u128;
 
-impl num_traits::CheckedMul for Timecode {
-    fn checked_mul(&self, v: &Self) -> Option<Self> {
-        todo!()
+        ((scaled_ticks + divisor / 2) / divisor) as usize
     }
 }
 
-impl num_traits::CheckedDiv for Timecode {
-    fn checked_div(&self, v: &Self) -> Option<Self> {
-        todo!()
-    }
-}
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `Timecode` Trait Implementations --
+//
+///////////////////////////////////////////////////////////////////////////
 
-impl num_traits::NumCast for Timecode {
-    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
-        todo!()
+impl Default for Timecode {
+    fn default() -> Self {
+        Self {
+            data: [TimecodeScalar::default(); TC_TOTAL_GROUPS],
+            fps: FrameRate::default(),
+            flags: 0,
+        }
     }
 }
 
-impl num_traits::ToPrimitive for Timecode {
-    fn to_i8(&self) -> Option<i8> {
-        todo!()
-    }
-
-    fn to_u8(&self) -> Option<u8> {
-        todo!()
-    }
-
-    fn to_i16(&self) -> Option<i16> {
-        todo!()
-    }
-
-    fn to_u16(&self) -> Option<u16> {
-        todo!()
-    }
-
-    fn to_i32(&self) -> Option<i32> {
-        todo!()
-    }
-
-    fn to_u32(&self) -> Option<u32> {
-        todo!()
-    }
-
-    fn to_i64(&self) -> Option<i64> {
-        todo!()
-    }
-
-    fn to_u64(&self) -> Option<u64> {
-        todo!()
-    }
-
-    fn to_i128(&self) -> Option<i128> {
-        todo!()
-    }
-
-    fn to_u128(&self) -> Option<u128> {
-        todo!()
-    }
-
-    fn to_f32(&self) -> Option<f32> {
-        todo!()
-    }
-
-    fn to_f64(&self) -> Option<f64> {
-        todo!()
-    }
+/// Formats the timecode as `hh:mm:ss:ff`, or `hh:mm:ss;ff` for drop-frame
+///
+/// Groups are padded to at least two digits, so hours past 99 are written
+/// in full (e.g. `120:59:59:24`).
+///
+/// The alternate form (`{:#}`) also includes the ticks group when it is
+/// nonzero, as `hh:mm:ss:ff.ttt`, which `Timecode::from_str` parses back.
+impl Display for Timecode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format = TimecodeFormat {
+            include_ticks: f.alternate(),
+            ..TimecodeFormat::default()
+        };
 
-    fn to_isize(&self) -> Option<isize> {
-        todo!()
+        write!(f, "{}", self.format_with(format))
     }
+}
 
-    fn to_usize(&self) -> Option<usize> {
-        todo!()
+impl EDLParseField<Self> for Timecode {
+    /// Parses a timecode at `FrameRate::default()`, or at 29.97 drop-frame
+    /// when the seconds and frames are delimited by `;`
+    ///
+    /// Fields whose frame rate is known, such as the session start
+    /// timecode, are parsed with `Timecode::from_str` instead.
+    fn parse_field(field_string: &str) -> Option<Self> {
+        let tc_string = field_string.trim();
+        let fps = if tc_string.contains(TC_STRING_DELIMITER_SEMICOLON) { FrameRate::Fps30(true) } else { FrameRate::default() };
+        Timecode::from_str(tc_string, fps).ok()
     }
 }
 
@@ -573,104 +763,198 @@ impl num_traits::ToPrimitive for Timecode {
 //
 ///////////////////////////////////////////////////////////////////////////
 
+/// Adds two timecodes by their tick counts
+///
+/// The result has the frame rate of the left operand; the right operand
+/// is converted to that frame rate first.
 impl std::ops::Add for Timecode {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
-        todo!()
+        Self::from_ticks(self.to_ticks() + rhs.ticks_at(self.fps), self.fps)
     }
 }
 
+/// Subtracts two timecodes by their tick counts, saturating at zero
+///
+/// The result has the frame rate of the left operand; the right operand
+/// is converted to that frame rate first.
+///
+/// Subtract references, `&self - &rhs`, for a `TimecodeDelta` that is
+/// negative when `rhs` is later.
 impl std::ops::Sub for Timecode {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
-        todo!()
+        Self::from_ticks(self.to_ticks().saturating_sub(rhs.ticks_at(self.fps)), self.fps)
+    }
+}
+
+/// Adds `rhs` in place, with the same frame rate rule as `Add`
+impl std::ops::AddAssign for Timecode {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// Subtracts `rhs` in place, with the same frame rate rule and saturation
+/// as `Sub`
+impl std::ops::SubAssign for Timecode {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
     }
 }
 
-impl std::ops::Mul for Timecode {
+/// Scales a timecode by multiplying its tick count, saturating at the
+/// largest tick count
+impl std::ops::Mul<u32> for Timecode {
     type Output = Self;
-    fn mul(self, rhs: Self) -> Self::Output {
-        todo!()
+    fn mul(self, rhs: u32) -> Self::Output {
+        Self::from_ticks(self.to_ticks().saturating_mul(rhs as usize), self.fps)
     }
 }
 
-impl std::ops::Div for Timecode {
+/// Divides a timecode by dividing its tick count, rounding down to the
+/// nearest tick
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero.
+impl std::ops::Div<u32> for Timecode {
     type Output = Self;
-    fn div(self, rhs: Self) -> Self::Output {
-        todo!()
+    fn div(self, rhs: u32) -> Self::Output {
+        assert!(rhs != 0, "attempt to divide a timecode by zero");
+        Self::from_ticks(self.to_ticks() / rhs as usize, self.fps)
     }
 }
 
+/// Wraps a timecode within a cycle of length `rhs` by the remainder of
+/// their tick counts, e.g. to find a position within a loop region
+///
+/// The result has the frame rate of the left operand; the right operand
+/// is converted to that frame rate first.
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero ticks long at the left operand's frame rate.
 impl Rem for Timecode {
     type Output = Self;
     fn rem(self, rhs: Self) -> Self::Output {
-        todo!()
+        let cycle_ticks = rhs.ticks_at(self.fps);
+        assert!(cycle_ticks != 0, "attempt to wrap a timecode within a zero length cycle");
+        Self::from_ticks(self.to_ticks() % cycle_ticks, self.fps)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `Timecode` Numeric Trait Implementations --
+//
+///////////////////////////////////////////////////////////////////////////
+
+// Only the `num_traits` that have a meaning for a point in time are
+// implemented; bitwise operations and the product or quotient of two
+// timecodes do not, so `Timecode` is not a `num_traits::PrimInt`.
+
+/// The zero timecode is `00:00:00:00` at `FrameRate::default()`
+impl num_traits::Zero for Timecode {
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.to_ticks() == 0
+    }
+
+    /// Sets every group to zero, keeping the frame rate
+    fn set_zero(&mut self) {
+        *self = Self::with_fps(self.fps);
     }
 }
 
+/// Bounded by `00:00:00:00` and the last tick of the largest hour, at
+/// `FrameRate::default()`
 impl Bounded for Timecode {
     fn min_value() -> Self {
-        todo!()
+        Self::default()
     }
 
     fn max_value() -> Self {
-        todo!()
+        Self::from_ticks(Self::max_ticks(FrameRate::default()), FrameRate::default())
     }
 }
 
-impl std::ops::Not for Timecode {
-    type Output = Self;
-    fn not(self) -> Self::Output {
-        todo!()
+impl num_traits::Saturating for Timecode {
+    fn saturating_add(self, v: Self) -> Self {
+        num_traits::SaturatingAdd::saturating_add(&self, &v)
     }
-}
 
-impl std::ops::BitAnd for Timecode {
-    type Output = Self;
-    fn bitand(self, rhs: Self) -> Self::Output {
-        todo!()
+    fn saturating_sub(self, v: Self) -> Self {
+        num_traits::SaturatingSub::saturating_sub(&self, &v)
     }
 }
 
-impl std::ops::BitOr for Timecode {
-    type Output = Self;
-    fn bitor(self, rhs: Self) -> Self::Output {
-        todo!()
+/// Adds like `Add`, saturating at the largest tick count at the left
+/// operand's frame rate
+impl num_traits::SaturatingAdd for Timecode {
+    fn saturating_add(&self, v: &Self) -> Self {
+        let ticks = self.to_ticks().saturating_add(v.ticks_at(self.fps));
+        Self::from_ticks(ticks.min(Self::max_ticks(self.fps)), self.fps)
     }
 }
 
-impl std::ops::BitXor for Timecode {
-    type Output = Self;
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        todo!()
+/// Subtracts like `Sub`, which already saturates at zero
+impl num_traits::SaturatingSub for Timecode {
+    fn saturating_sub(&self, v: &Self) -> Self {
+        *self - *v
     }
 }
 
-impl std::ops::Shl for Timecode {
-    type Output = Self;
-    fn shl(self, rhs: Self) -> Self::Output {
-        todo!()
+/// Adds like `Add`, or returns `None` past the largest tick count at the
+/// left operand's frame rate
+impl num_traits::CheckedAdd for Timecode {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        self.to_ticks()
+            .checked_add(v.ticks_at(self.fps))
+            .filter(|&ticks| ticks <= Self::max_ticks(self.fps))
+            .map(|ticks| Self::from_ticks(ticks, self.fps))
     }
 }
 
-impl std::ops::Shr for Timecode {
-    type Output = Self;
-    fn shr(self, rhs: Self) -> Self::Output {
-        todo!()
+/// Subtracts like `Sub`, or returns `None` instead of saturating at zero
+impl num_traits::CheckedSub for Timecode {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        self.to_ticks()
+            .checked_sub(v.ticks_at(self.fps))
+            .map(|ticks| Self::from_ticks(ticks, self.fps))
     }
 }
 
-impl std::ops::Shl<usize> for Timecode {
-    type Output = Self;
-    fn shl(self, rhs: usize) -> Self::Output {
-        todo!()
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `Timecode` Serde Implementations --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// Serialized form of a `Timecode`: its alternate display string, which
+/// includes the ticks group when it has ticks, and its frame rate
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct TimecodeRepr {
+    timecode: String,
+    fps: FrameRate,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Timecode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TimecodeRepr { timecode: format!("{:#}", self), fps: self.fps }.serialize(serializer)
     }
 }
 
-impl std::ops::Shr<usize> for Timecode {
-    type Output = Self;
-    fn shr(self, rhs: usize) -> Self::Output {
-        todo!()
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Timecode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = TimecodeRepr::deserialize(deserializer)?;
+        Timecode::from_str(&repr.timecode, repr.fps).map_err(serde::de::Error::custom)
     }
 }
 
@@ -704,6 +988,47 @@ mod tests {
         assert_eq!(timecode.flags, TC_FLAGS_DEFAULT);
     }
 
+    #[test]
+    fn compare_across_frame_rates() {
+        let pal_second = Timecode::from_str("00:00:01:00", FrameRate::Fps25).unwrap();
+        let ntsc_second = Timecode::from_str("00:00:01:00", FrameRate::Fps30(false)).unwrap();
+        let pal_half = Timecode::from_str("00:00:00:12.050", FrameRate::Fps25).unwrap();
+        let ntsc_half = Timecode::from_str("00:00:00:15", FrameRate::Fps30(false)).unwrap();
+        let ntsc_late = Timecode::from_str("00:00:00:29", FrameRate::Fps30(false)).unwrap();
+
+        assert!(pal_second.cmp_time(&ntsc_second).is_eq());
+        assert!(pal_half.cmp_time(&ntsc_half).is_eq());
+        assert!(ntsc_late.cmp_time(&pal_second).is_lt());
+        assert!(pal_second.cmp_time(&ntsc_late).is_gt());
+
+        assert_eq!(pal_second.max(ntsc_second), ntsc_second);
+        assert_eq!(pal_second.min(ntsc_second), pal_second);
+        assert_eq!(pal_second.max(ntsc_late), pal_second);
+        assert_eq!(pal_second.min(ntsc_late), ntsc_late);
+
+        assert!(ntsc_half.is_between(pal_half, pal_second));
+        assert!(!ntsc_second.is_between(pal_half, pal_second));
+        assert!(ntsc_second.is_between_inclusive(pal_half, pal_second));
+        assert!(ntsc_late.is_between(ntsc_half, pal_second));
+        assert!(!pal_half.is_between_inclusive(ntsc_late, pal_second));
+    }
+
+    #[test]
+    fn try_parts_constructor() {
+        let timecode = Timecode::try_from_parts(&[10, 59, 59, 24, 99], FrameRate::Fps25).expect("groups should be in range");
+        assert_eq!(timecode, Timecode::from_parts(&[10, 59, 59, 24, 99], FrameRate::Fps25));
+        assert!(Timecode::try_from_parts(&[0, 1, 0, 2, 0], FrameRate::Fps30(true)).unwrap().is_drop_frame());
+
+        let out_of_range = Err(TimecodeParseError::GroupOutOfRange);
+        assert_eq!(Timecode::try_from_parts(&[0, 99, 99, 99, 999], FrameRate::Fps25), out_of_range);
+        assert_eq!(Timecode::try_from_parts(&[0, 60, 0, 0, 0], FrameRate::Fps25), out_of_range);
+        assert_eq!(Timecode::try_from_parts(&[0, 0, 60, 0, 0], FrameRate::Fps25), out_of_range);
+        assert_eq!(Timecode::try_from_parts(&[0, 0, 0, 25, 0], FrameRate::Fps25), out_of_range);
+        assert_eq!(Timecode::try_from_parts(&[0, 0, 0, 0, 100], FrameRate::Fps25), out_of_range);
+        assert_eq!(Timecode::try_from_parts(&[0, 1, 0, 0, 0], FrameRate::Fps30(true)), out_of_range);
+        assert!(Timecode::try_from_parts(&[0, 0, 0, 29, 0], FrameRate::Fps30(false)).is_ok());
+    }
+
     #[test]
     fn str_constructor() {
         let timecode_with_ticks = Timecode::from_str("00:01:02:03:04", FrameRate::Fps25).expect("timecode must be constructible with a timecode string slice");
@@ -767,4 +1092,379 @@ mod tests {
         assert_eq!("13:12:32:42", format!("{}", timecode_new));
         assert_eq!("01:02:03;04", format!("{}", timecode_dropframe));
     }
+
+    #[test]
+    fn frames_conversion_dropframe() {
+        let timecode = Timecode::from_str("00:10:00;00", FrameRate::Fps30(true)).expect("timecode must be constructible with a drop-frame timecode string slice");
+        let after_skip = Timecode::from_str("00:01:00;02", FrameRate::Fps30(true)).expect("timecode must be constructible with a drop-frame timecode string slice");
+
+        assert_eq!(timecode.to_frames(), 17982);
+        assert_eq!(after_skip.to_frames(), 1800);
+        assert_eq!(Timecode::from_frames(17982, FrameRate::Fps30(true)), timecode);
+        assert_eq!(Timecode::from_frames(1800, FrameRate::Fps30(true)), after_skip);
+    }
+
+    #[test]
+    fn add_sub_operators() {
+        let timecode = Timecode::from_parts(&[0, 0, 59, 20, 0], FrameRate::Fps25);
+        let offset = Timecode::from_parts(&[0, 0, 0, 10, 0], FrameRate::Fps25);
+
+        assert_eq!(format!("{}", timecode + offset), "00:01:00:05");
+        assert_eq!(format!("{}", timecode - offset), "00:00:59:10");
+        assert_eq!(offset - timecode, Timecode::with_fps(FrameRate::Fps25));
+        assert_eq!(format!("{}", timecode.add_frames(5)), "00:01:00:00");
+        assert_eq!(timecode.sub_frames(10_000), Timecode::with_fps(FrameRate::Fps25));
+    }
+
+    #[test]
+    fn add_sub_assign_operators() {
+        let one_frame = Timecode::from_parts(&[0, 0, 0, 1, 0], FrameRate::Fps25);
+        let mut timecode = Timecode::from_parts(&[0, 0, 59, 20, 0], FrameRate::Fps25);
+
+        for _ in 0..30 {
+            timecode += one_frame;
+        }
+        assert_eq!(format!("{}", timecode), "00:01:01:00");
+
+        let two_frames_at_50fps = Timecode::from_parts(&[0, 0, 0, 2, 0], FrameRate::Fps50);
+        timecode -= two_frames_at_50fps;
+        assert_eq!(format!("{}", timecode), "00:01:00:24");
+        assert_eq!(timecode.frame_rate(), FrameRate::Fps25);
+
+        for _ in 0..10_000 {
+            timecode -= one_frame;
+        }
+        assert_eq!(timecode, Timecode::with_fps(FrameRate::Fps25));
+    }
+
+    #[test]
+    fn scalar_mul_div_operators() {
+        let second = Timecode::from_parts(&[0, 0, 1, 0, 0], FrameRate::Fps25);
+
+        assert_eq!(format!("{}", second * 3), "00:00:03:00");
+        assert_eq!(format!("{}", (second * 90) / 2), "00:00:45:00");
+
+        let quarter = second / 4;
+        assert_eq!((quarter.frames::<u32>(), quarter.ticks::<u32>()), (6, 25));
+
+        let third = Timecode::from_parts(&[0, 0, 0, 1, 0], FrameRate::Fps25) / 3;
+        assert_eq!((third.frames::<u32>(), third.ticks::<u32>()), (0, 33));
+        assert_eq!((third * 3).to_ticks(), 99);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide a timecode by zero")]
+    fn scalar_div_by_zero() {
+        let _ = Timecode::from_parts(&[0, 0, 1, 0, 0], FrameRate::Fps25) / 0;
+    }
+
+    #[test]
+    fn rem_operator() {
+        let timecode = |tc_string: &str, fps: FrameRate| Timecode::from_str(tc_string, fps).expect("test timecode should be valid");
+
+        assert_eq!(timecode("00:00:10:00", FrameRate::Fps25) % timecode("00:00:03:00", FrameRate::Fps25), timecode("00:00:01:00", FrameRate::Fps25));
+        assert_eq!(timecode("00:00:09:00", FrameRate::Fps25) % timecode("00:00:03:00", FrameRate::Fps25), timecode("00:00:00:00", FrameRate::Fps25));
+        assert_eq!(timecode("00:00:02:10", FrameRate::Fps25) % timecode("00:00:03:00", FrameRate::Fps25), timecode("00:00:02:10", FrameRate::Fps25));
+
+        let wrapped = timecode("00:00:10:00", FrameRate::Fps25) % timecode("00:00:03:00", FrameRate::Fps50);
+        assert_eq!(wrapped, timecode("00:00:01:00", FrameRate::Fps25));
+        assert_eq!(wrapped.frame_rate(), FrameRate::Fps25);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to wrap a timecode within a zero length cycle")]
+    fn rem_by_zero_length_cycle() {
+        let _ = Timecode::from_parts(&[0, 0, 1, 0, 0], FrameRate::Fps25) % Timecode::with_fps(FrameRate::Fps25);
+    }
+
+    #[test]
+    fn clamp_to_range() {
+        let timecode = |tc_string: &str, fps: FrameRate| Timecode::from_str(tc_string, fps).expect("test timecode should be valid");
+        let start = timecode("01:00:00:00", FrameRate::Fps25);
+        let end = timecode("01:00:10:00", FrameRate::Fps25);
+
+        let below = timecode("00:59:59:24", FrameRate::Fps25);
+        let inside = timecode("01:00:05:12", FrameRate::Fps25);
+        let above = timecode("01:00:10:01", FrameRate::Fps25);
+        assert_eq!(below.clamp_to_range(start, end), start);
+        assert_eq!(inside.clamp_to_range(start, end), inside);
+        assert_eq!(above.clamp_to_range(start, end), end);
+
+        let below_at_30 = timecode("00:59:59:29", FrameRate::Fps30(false));
+        let inside_at_30 = timecode("01:00:05:29", FrameRate::Fps30(false));
+        let above_at_30 = timecode("01:00:10:01", FrameRate::Fps30(false));
+        assert_eq!(below_at_30.clamp_to_range(start, end), timecode("01:00:00:00", FrameRate::Fps30(false)));
+        assert_eq!(inside_at_30.clamp_to_range(start, end), inside_at_30);
+        assert_eq!(above_at_30.clamp_to_range(start, end), timecode("01:00:10:00", FrameRate::Fps30(false)));
+    }
+
+    #[test]
+    fn is_drop_frame_after_set_frame_rate() {
+        let mut timecode = Timecode::default();
+        assert!(!timecode.is_drop_frame());
+
+        timecode.set_frame_rate(FrameRate::Fps30(true));
+        assert!(timecode.is_drop_frame());
+        assert_eq!(timecode.frame_rate(), FrameRate::Fps30(true));
+        assert!(Timecode::from_str("01:00:00;00", FrameRate::Fps30(true)).unwrap().is_drop_frame());
+    }
+
+    #[test]
+    fn set_frame_rate_updates_drop_frame_flag() {
+        let mut timecode = Timecode::from_parts(&[1, 0, 0, 0, 0], FrameRate::Fps25);
+        assert_eq!(format!("{}", timecode), "01:00:00:00");
+
+        timecode.set_frame_rate(FrameRate::Fps30(true));
+        assert!(timecode.is_drop_frame());
+        assert_eq!(format!("{}", timecode), "01:00:00;00");
+        assert_eq!(timecode.to_frames(), Timecode::from_str("01:00:00;00", FrameRate::Fps30(true)).unwrap().to_frames());
+
+        timecode.set_frame_rate(FrameRate::Fps30(false));
+        assert!(!timecode.is_drop_frame());
+        assert_eq!(format!("{}", timecode), "01:00:00:00");
+        assert_eq!(timecode, Timecode::from_parts(&[1, 0, 0, 0, 0], FrameRate::Fps30(false)));
+    }
+
+    #[test]
+    fn ticks_delimiter_round_trip() {
+        let timecode = Timecode::from_str("01:02:03:04.050", FrameRate::Fps25).expect("timecode with ticks should parse");
+        assert_eq!(timecode.data, [1, 2, 3, 4, 50]);
+        assert_eq!(format!("{:#}", timecode), "01:02:03:04.050");
+        assert_eq!(format!("{}", timecode), "01:02:03:04");
+        assert_eq!(Timecode::from_str(&format!("{:#}", timecode), FrameRate::Fps25), Ok(timecode));
+
+        assert_eq!(Timecode::from_str("01:02:03:04:50", FrameRate::Fps25), Ok(timecode));
+        assert_eq!(format!("{:#}", Timecode::from_str("01:02:03:04.000", FrameRate::Fps25).unwrap()), "01:02:03:04");
+        assert_eq!(Timecode::from_str("01:02.050", FrameRate::Fps25), Err(TimecodeParseError::InvalidGroupCount));
+        assert_eq!(Timecode::from_str("01:02:03:04:05.050", FrameRate::Fps25), Err(TimecodeParseError::InvalidGroupCount));
+    }
+
+    #[test]
+    fn str_constructor_dropframe_hour_widths() {
+        let single_digit = Timecode::from_str("1:02:03;04", FrameRate::Fps30(true)).expect("single digit hours should parse");
+        assert_eq!(single_digit.data, [1, 2, 3, 4, 0]);
+        assert!(single_digit.check_flag(TC_FLAGS_DROPFRAME));
+
+        let multi_digit = Timecode::from_str("100:02:03;04", FrameRate::Fps30(true)).expect("three digit hours should parse");
+        assert_eq!(multi_digit.data, [100, 2, 3, 4, 0]);
+        assert!(multi_digit.check_flag(TC_FLAGS_DROPFRAME));
+
+        let with_ticks = Timecode::from_str("1:02:03;04.050", FrameRate::Fps30(true)).expect("single digit hours with ticks should parse");
+        assert_eq!(with_ticks.data, [1, 2, 3, 4, 50]);
+        assert!(with_ticks.check_flag(TC_FLAGS_DROPFRAME));
+
+        assert_eq!(Timecode::from_str("1;02:03:04", FrameRate::Fps30(true)), Err(TimecodeParseError::InvalidDelimiter));
+        assert_eq!(Timecode::from_str("01:02;03:04", FrameRate::Fps30(true)), Err(TimecodeParseError::InvalidDelimiter));
+        assert_eq!(Timecode::from_str("01:02:03;04;05", FrameRate::Fps30(true)), Err(TimecodeParseError::InvalidDelimiter));
+    }
+
+    #[test]
+    fn str_constructor_wide_hours() {
+        let timecode = Timecode::from_str("120:59:59:24", FrameRate::Fps25).expect("timecode with three digit hours should parse");
+        assert_eq!(timecode.data, [120, 59, 59, 24, 0]);
+        assert_eq!(timecode.hours::<u32>(), 120);
+        assert_eq!(timecode.to_string(), "120:59:59:24");
+        assert_eq!(timecode.to_frames(), (120 * 3600 + 59 * 60 + 59) * 25 + 24);
+        assert_eq!(Timecode::from_frames(timecode.to_frames(), FrameRate::Fps25), timecode);
+
+        let beyond_u8 = Timecode::from_str("300:00:00:00", FrameRate::Fps25).expect("hours beyond 255 should parse");
+        assert_eq!(beyond_u8.to_string(), "300:00:00:00");
+        assert_eq!(beyond_u8.hours::<u32>(), 300);
+        assert_eq!(beyond_u8.hours::<u64>(), 300);
+    }
+
+    #[test]
+    fn str_constructor_overflowing_frames() {
+        let strict = Timecode::from_str("00:00:00:25", FrameRate::Fps25);
+        let normalized = Timecode::from_str_with_options(
+            "00:00:59:25",
+            FrameRate::Fps25,
+            TimecodeParseOptions { normalize_on_parse: true },
+        ).expect("overflowing timecode must be normalized when requested");
+
+        assert_eq!(strict, Err(TimecodeParseError::GroupOutOfRange));
+        assert_eq!(normalized, Timecode::from_parts(&[0, 1, 0, 0, 0], FrameRate::Fps25));
+    }
+
+    #[test]
+    fn add_sub_frames_dropframe_minute_boundary() {
+        let fps = FrameRate::Fps30(true);
+        let timecode = Timecode::from_str("00:00:59;28", fps).expect("timecode must be constructible with a drop-frame timecode string slice");
+
+        let stepped = (1..=3).map(|n| format!("{}", timecode.add_frames(n))).collect::<Vec<_>>();
+        assert_eq!(stepped, ["00:00:59;29", "00:01:00;02", "00:01:00;03"]);
+        assert_eq!(format!("{}", timecode.add_frames(2).sub_frames(1)), "00:00:59;29");
+        assert_eq!(format!("{}", Timecode::from_str("00:09:59;29", fps).unwrap().add_frames(1)), "00:10:00;00");
+
+        let mut current = Timecode::with_fps(fps);
+        for _ in 0..(11 * 60 * 30) {
+            let next = current.add_frames(1);
+            assert!(next.groups_in_range(), "{} is not a legal drop-frame label", next);
+            assert_eq!(next.to_frames(), current.to_frames() + 1);
+            current = next;
+        }
+    }
+
+    #[test]
+    fn str_constructor_dropped_label() {
+        let fps = FrameRate::Fps30(true);
+        let normalized = Timecode::from_str_with_options("00:01:00;00", fps, TimecodeParseOptions { normalize_on_parse: true })
+            .expect("dropped label must be normalized when requested");
+
+        assert_eq!(Timecode::from_str("00:01:00;00", fps), Err(TimecodeParseError::GroupOutOfRange));
+        assert_eq!(format!("{}", normalized), "00:01:00;02");
+        assert!(Timecode::from_str("00:10:00;00", fps).is_ok());
+    }
+
+    #[test]
+    fn rational_conversion_one_hour() {
+        let one_hour = Timecode::from_str("01:00:00;00", FrameRate::Fps30(true)).expect("timecode must be constructible with a drop-frame timecode string slice");
+        assert_eq!(one_hour.to_frames(), 107892);
+        assert_eq!(Timecode::from_frames(107892, FrameRate::Fps30(true)), one_hour);
+
+        let film_hour = Timecode::from_frames(86400, FrameRate::Fps24(true));
+        let converted = Timecode::with_fps(FrameRate::Fps30(true)) + film_hour;
+        assert_eq!(converted.to_frames(), 108000);
+        assert_eq!(converted.to_ticks(), 108000 * TC_TICK_RESOLUTION);
+    }
+
+    #[test]
+    fn saturating_add_frames_clamps_to_day() {
+        let last_frame_of_day = Timecode::from_str("23:59:59:24", FrameRate::Fps25).unwrap();
+        assert_eq!(last_frame_of_day.saturating_add_frames(1), last_frame_of_day);
+        assert_eq!(last_frame_of_day.saturating_add_frames(i64::MAX), last_frame_of_day);
+        assert_eq!(last_frame_of_day.saturating_add_frames(-24), Timecode::from_str("23:59:59:00", FrameRate::Fps25).unwrap());
+
+        let start = Timecode::from_str("00:00:00:02", FrameRate::Fps25).unwrap();
+        assert_eq!(start.saturating_add_frames(-3), Timecode::with_fps(FrameRate::Fps25));
+        assert_eq!(start.saturating_add_frames(i64::MIN), Timecode::with_fps(FrameRate::Fps25));
+        assert_eq!(start.saturating_add_frames(23), Timecode::from_str("00:00:01:00", FrameRate::Fps25).unwrap());
+
+        let dropframe_end = Timecode::from_str("23:59:59;29", FrameRate::Fps30(true)).unwrap();
+        assert_eq!(dropframe_end.saturating_add_frames(5), dropframe_end);
+        assert_eq!(
+            Timecode::from_str("00:00:59;29", FrameRate::Fps30(true)).unwrap().saturating_add_frames(1),
+            Timecode::from_str("00:01:00;02", FrameRate::Fps30(true)).unwrap()
+        );
+    }
+
+    #[test]
+    fn numeric_traits() {
+        use num_traits::{CheckedAdd, CheckedSub, Saturating, SaturatingAdd, SaturatingSub, Zero};
+
+        fn assert_timecode_arithmetic<T>()
+        where
+            T: Zero + Bounded + Saturating + SaturatingAdd + SaturatingSub + CheckedAdd + CheckedSub
+                + std::ops::Rem<Output = T> + std::ops::Mul<u32, Output = T> + std::ops::Div<u32, Output = T>
+        {}
+
+        assert_timecode_arithmetic::<Timecode>();
+
+        let one_second = Timecode::from_str("00:00:01:00", FrameRate::Fps25).unwrap();
+        let two_seconds = one_second * 2;
+
+        assert!(Timecode::zero().is_zero());
+        assert!(!one_second.is_zero());
+        assert_eq!(Timecode::min_value(), Timecode::zero());
+
+        assert_eq!(one_second.checked_sub(&two_seconds), None);
+        assert_eq!(two_seconds.checked_sub(&one_second), Some(one_second));
+        assert_eq!(SaturatingSub::saturating_sub(&one_second, &two_seconds), Timecode::with_fps(FrameRate::Fps25));
+
+        let max_value = Timecode::max_value();
+        assert_eq!(max_value.hours::<u32>(), TimecodeScalar::MAX);
+        assert_eq!(max_value.checked_add(&one_second), None);
+        assert_eq!(SaturatingAdd::saturating_add(&max_value, &one_second), max_value);
+        assert_eq!(one_second.checked_add(&one_second), Some(two_seconds));
+    }
+
+    #[test]
+    fn format_with_layouts() {
+        let timecode = Timecode::from_str("01:02:03:04.050", FrameRate::Fps25).unwrap();
+        let dropframe = Timecode::from_str("01:02:03;04", FrameRate::Fps30(true)).unwrap();
+
+        assert_eq!(timecode.format_with(TimecodeFormat::default()), timecode.to_string());
+        assert_eq!(dropframe.format_with(TimecodeFormat::default()), "01:02:03;04");
+
+        let without_frames = TimecodeFormat { include_frames: false, ..TimecodeFormat::default() };
+        assert_eq!(timecode.format_with(without_frames), "01:02:03");
+        assert_eq!(dropframe.format_with(without_frames), "01:02:03");
+
+        let compact = TimecodeFormat { delimiter: "", dropframe_delimiter: "", ..TimecodeFormat::default() };
+        assert_eq!(timecode.format_with(compact), "01020304");
+        assert_eq!(dropframe.format_with(compact), "01020304");
+        assert_eq!(timecode.format_with(TimecodeFormat { include_ticks: true, ..compact }), "01020304.050");
+
+        let frames_only = TimecodeFormat { frames_only: true, ..TimecodeFormat::default() };
+        assert_eq!(timecode.format_with(frames_only), "93079");
+    }
+
+    #[test]
+    fn fractional_rate_ticks_use_nominal_fps() {
+        let one_second = Timecode::from_str("00:00:01:00", FrameRate::Fps24(true)).unwrap();
+        assert_eq!(one_second.to_frames(), 24);
+        assert_eq!(one_second.to_ticks(), 24 * TC_TICK_RESOLUTION);
+
+        let last_frame = Timecode::from_str("00:00:00:23.050", FrameRate::Fps24(true)).unwrap();
+        assert_eq!(last_frame.to_ticks(), 23 * TC_TICK_RESOLUTION + 50);
+        assert_eq!(Timecode::from_ticks(one_second.to_ticks(), FrameRate::Fps24(true)).to_frames(), 24);
+    }
+
+    #[test]
+    fn duration_conversion_integer_rate() {
+        use std::time::Duration;
+
+        let timecode = Timecode::from_str("01:00:01:12.050", FrameRate::Fps25).unwrap();
+        assert_eq!(timecode.to_duration(), Duration::from_millis(3_601_500));
+        assert_eq!(Timecode::from_duration(timecode.to_duration(), FrameRate::Fps25), timecode);
+        assert_eq!(Timecode::from_duration(Duration::from_secs(2), FrameRate::Fps25).to_frames(), 50);
+    }
+
+    #[test]
+    fn duration_conversion_dropframe_rate() {
+        use std::time::Duration;
+
+        let nominal_hour = Timecode::from_str("01:00:00:00", FrameRate::Fps30(true)).unwrap();
+        assert_eq!(nominal_hour.to_duration(), Duration::from_millis(3_603_600));
+        assert_eq!(Timecode::from_duration(Duration::from_millis(3_603_600), FrameRate::Fps30(true)).to_ticks(), nominal_hour.to_ticks());
+
+        let dropframe_hour = Timecode::from_str("01:00:00;00", FrameRate::Fps30(true)).unwrap();
+        assert_eq!(dropframe_hour.to_duration(), Duration::from_nanos(3_599_996_400_000));
+        assert_eq!(Timecode::from_duration(dropframe_hour.to_duration(), FrameRate::Fps30(true)), dropframe_hour);
+
+        for ticks in [1, 99, 12_345, 10_789_201] {
+            let timecode = Timecode::from_ticks(ticks, FrameRate::Fps24(true));
+            assert_eq!(Timecode::from_duration(timecode.to_duration(), FrameRate::Fps24(true)).to_ticks(), ticks);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_round_trip() {
+        let timecode = Timecode::from_parts(&[1, 2, 3, 4, 50], FrameRate::Fps30(true));
+        let json = serde_json::to_string(&timecode).expect("timecode should serialize to JSON");
+
+        assert_eq!(json, r#"{"timecode":"01:02:03;04.050","fps":"29.97 Drop Frame"}"#);
+        assert_eq!(serde_json::from_str::<Timecode>(&json).expect("timecode should deserialize from JSON"), timecode);
+
+        let legacy_json = r#"{"timecode":"01:02:03;04:50","fps":"29.97 Drop Frame"}"#;
+        assert_eq!(serde_json::from_str::<Timecode>(legacy_json).expect("colon delimited ticks should still deserialize"), timecode);
+    }
+
+    #[test]
+    fn parse_field_trait() {
+        fn parse<T: EDLParseField<T>>(field_string: &str) -> Option<T> {
+            T::parse_field(field_string)
+        }
+
+        let timecode = parse::<Timecode>(" 01:00:10:12 ").expect("timecode field should parse");
+        assert_eq!(timecode, Timecode::from_str("01:00:10:12", FrameRate::default()).unwrap());
+
+        let drop_frame_timecode = parse::<Timecode>("01:00:10;12").expect("drop-frame timecode field should parse");
+        assert_eq!(drop_frame_timecode.frame_rate(), FrameRate::Fps30(true));
+        assert!(drop_frame_timecode.check_flag(TC_FLAGS_DROPFRAME));
+
+        assert_eq!(parse::<Timecode>("01:00:10:25"), None);
+        assert_eq!(parse::<Timecode>("01:xx:10:12"), None);
+    }
 }
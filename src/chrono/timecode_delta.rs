@@ -0,0 +1,218 @@
+// Copyright (C) Stefan Olivier
+// <https://stefanolivier.com>
+
+use std::fmt::Display;
+
+use crate::chrono::Timecode;
+use crate::chrono::timecode::TC_TICK_RESOLUTION;
+use crate::format::FrameRate;
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `TimecodeDelta` Structure Definition --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// A signed distance between two points in time, such as an edit offset
+/// or a pre-roll, stored as a sub-frame tick count at a frame rate
+///
+/// Where `Timecode` is a point in time and cannot go below zero, a
+/// `TimecodeDelta` may be negative, and adding a negative delta to a
+/// `Timecode` moves it earlier.
+///
+/// The delta between two timecodes is `&time_out - &time_in`, or
+/// `Timecode::signed_sub`. Subtracting timecodes by value keeps returning a
+/// saturating `Timecode`, which durations throughout the crate, such as
+/// `EDLTrackEvent::duration`, rely on.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimecodeDelta {
+    ticks: i64,
+    fps: FrameRate,
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `TimecodeDelta` Implementation --
+//
+///////////////////////////////////////////////////////////////////////////
+
+impl TimecodeDelta {
+    /// Constructs a delta of `frames` frames at the specified frame rate
+    pub fn from_frames(frames: i64, fps: FrameRate) -> Self {
+        Self::from_ticks(frames * TC_TICK_RESOLUTION as i64, fps)
+    }
+
+    /// Constructs a delta of `ticks` sub-frame ticks at the specified frame
+    /// rate
+    pub fn from_ticks(ticks: i64, fps: FrameRate) -> Self {
+        Self { ticks, fps }
+    }
+
+    /// The number of whole frames in the delta, rounded towards zero
+    pub fn to_frames(&self) -> i64 {
+        self.ticks / TC_TICK_RESOLUTION as i64
+    }
+
+    pub fn to_ticks(&self) -> i64 {
+        self.ticks
+    }
+
+    pub fn frame_rate(&self) -> FrameRate {
+        self.fps
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.ticks < 0
+    }
+
+    /// The length of the delta, ignoring its sign, as a `Timecode`
+    pub fn magnitude(&self) -> Timecode {
+        Timecode::from_ticks(self.ticks.unsigned_abs() as usize, self.fps)
+    }
+
+    /// Converts the delta to the equivalent signed tick count at another
+    /// frame rate
+    fn ticks_at(&self, fps: FrameRate) -> i64 {
+        let ticks = self.magnitude().ticks_at(fps) as i64;
+        if self.is_negative() { -ticks } else { ticks }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `TimecodeDelta` Trait Implementations --
+//
+///////////////////////////////////////////////////////////////////////////
+
+impl std::ops::Neg for TimecodeDelta {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::from_ticks(-self.ticks, self.fps)
+    }
+}
+
+/// Formats the delta like its `magnitude`, with a leading `-` when it is
+/// negative
+impl Display for TimecodeDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        if f.alternate() {
+            write!(f, "{}{:#}", sign, self.magnitude())
+        } else {
+            write!(f, "{}{}", sign, self.magnitude())
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `Timecode` Delta Operator Overloads --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// Moves a timecode by a signed delta, saturating at zero
+///
+/// The result has the frame rate of the timecode; the delta is converted
+/// to that frame rate first.
+impl std::ops::Add<TimecodeDelta> for Timecode {
+    type Output = Self;
+    fn add(self, rhs: TimecodeDelta) -> Self::Output {
+        let ticks = self.to_ticks() as i64 + rhs.ticks_at(self.frame_rate());
+        Self::from_ticks(ticks.max(0) as usize, self.frame_rate())
+    }
+}
+
+/// Moves a timecode by the negation of a signed delta, saturating at zero
+impl std::ops::Sub<TimecodeDelta> for Timecode {
+    type Output = Self;
+    fn sub(self, rhs: TimecodeDelta) -> Self::Output {
+        self + -rhs
+    }
+}
+
+/// The signed distance from `rhs` to `self`, at `self`'s frame rate; see
+/// `Timecode::signed_sub`
+///
+/// Clippy's `op_ref` lint suggests dropping the references here, which
+/// would select the saturating `Timecode` subtraction instead.
+impl std::ops::Sub for &Timecode {
+    type Output = TimecodeDelta;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.signed_sub(*rhs)
+    }
+}
+
+impl std::ops::AddAssign<TimecodeDelta> for Timecode {
+    fn add_assign(&mut self, rhs: TimecodeDelta) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::SubAssign<TimecodeDelta> for Timecode {
+    fn sub_assign(&mut self, rhs: TimecodeDelta) {
+        *self = *self - rhs;
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `TimecodeDelta` Unit Tests --
+//
+///////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timecode(tc_string: &str) -> Timecode {
+        Timecode::from_str(tc_string, FrameRate::Fps25).expect("test timecode should be valid")
+    }
+
+    #[test]
+    fn signed_sub_and_add_delta() {
+        let time_in = timecode("01:00:00:00");
+        let time_out = timecode("01:00:02:10");
+
+        let forward = time_out.signed_sub(time_in);
+        let backward = time_in.signed_sub(time_out);
+        assert_eq!(forward.to_frames(), 60);
+        assert_eq!(backward.to_frames(), -60);
+        assert_eq!(backward, -forward);
+        assert_eq!(format!("{}", backward), "-00:00:02:10");
+
+        assert_eq!(time_in + forward, time_out);
+        assert_eq!(time_out + backward, time_in);
+        assert_eq!(time_out - forward, time_in);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)] // the by-value operands give a saturating `Timecode` instead
+    fn sub_timecode_refs() {
+        let time_in = timecode("01:00:00:00");
+        let time_out = timecode("01:00:02:10");
+
+        assert_eq!(&time_out - &time_in, time_out.signed_sub(time_in));
+        assert_eq!((&time_in - &time_out).to_frames(), -60);
+        assert_eq!(time_in - time_out, timecode("00:00:00:00"));
+
+        let ntsc_time_out = Timecode::from_str("01:00:02:12", FrameRate::Fps30(false)).expect("test timecode should be valid");
+        assert_eq!(&ntsc_time_out - &time_in, TimecodeDelta::from_frames(72, FrameRate::Fps30(false)));
+    }
+
+    #[test]
+    fn negative_delta_moves_earlier() {
+        let mut position = timecode("01:00:00:00");
+        let trim_left = TimecodeDelta::from_frames(-5, FrameRate::Fps25);
+
+        position += trim_left;
+        assert_eq!(position, timecode("00:59:59:20"));
+
+        position -= trim_left;
+        assert_eq!(position, timecode("01:00:00:00"));
+
+        let pre_roll_at_50fps = TimecodeDelta::from_frames(-100, FrameRate::Fps50);
+        assert_eq!(position + pre_roll_at_50fps, timecode("00:59:58:00"));
+        assert_eq!(timecode("00:00:01:00") + pre_roll_at_50fps, timecode("00:00:00:00"));
+    }
+}
@@ -6,9 +6,40 @@
 mod protools;
 
 pub use protools::{
+    EDLError as EDLProtoolsError,
+    EDLWarning as EDLProtoolsWarning,
+    EDLValidationWarning as EDLProtoolsValidationWarning,
     EDLParser as EDLProtoolsParser,
     EDLSession as EDLProtoolsSession,
+    EDLSessionBuilder as EDLProtoolsSessionBuilder,
     ParseField as EDLParseField,
+    ParseTable as EDLParseTable,
+    EDLSESSION_FLAG_DEFAULT,
+    EDLSESSION_FLAG_CONTAINS_PLUGIN,
+    EDLSESSION_FLAG_CONTAINS_ONLINE_FILES,
+    EDLSESSION_FLAG_CONTAINS_OFFLINE_FILES,
+    EDLSESSION_FLAG_CONTAINS_ONLINE_CLIPS,
+    EDLSESSION_FLAG_CONTAINS_TRACKS,
+    EDLSESSION_FLAG_CONTAINS_MARKERS,
+};
+
+pub use protools::{
+    EDLClip as EDLProtoolsClip,
+    EDLFileList as EDLProtoolsFileList,
+    EDLMarker as EDLProtoolsMarker,
+    EDLMarkerLocation as EDLProtoolsMarkerLocation,
+    EDLMediaFile as EDLProtoolsMediaFile,
+    EDLPlugin as EDLProtoolsPlugin,
+    EDLPluginFormat as EDLProtoolsPluginFormat,
+    EDLPluginInstance as EDLProtoolsPluginInstance,
+    EDLSourceLine as EDLProtoolsSourceLine,
+    EDLTrack as EDLProtoolsTrack,
+    EDLTrackDelay as EDLProtoolsTrackDelay,
+    EDLTrackEvent as EDLProtoolsTrackEvent,
+    EDLTrackState as EDLProtoolsTrackState,
+    EDLUnit as EDLProtoolsUnit,
+    EDLEVENT_FLAG_DEFAULT,
+    EDLEVENT_FLAG_HAS_TIMESTAMP,
 };
 
 pub mod encoding {
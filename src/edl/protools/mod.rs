@@ -4,10 +4,12 @@
 #![allow(dead_code, unused_imports, unused_braces)]
 
 mod parser;
+mod parser_error;
 mod parser_types;
 mod parser_traits;
 mod session;
 mod session_types;
+mod writer;
 
 ///////////////////////////////////////////////////////////////////////////
 //
@@ -17,6 +19,18 @@ mod session_types;
 
 pub use parser::EDLParser;
 
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLParser` Error Types Module Interface --
+//
+///////////////////////////////////////////////////////////////////////////
+
+pub use parser_error::{
+    EDLError,
+    EDLValidationWarning,
+    EDLWarning,
+};
+
 ///////////////////////////////////////////////////////////////////////////
 //
 //  -- @SECTION `EDLParser` Internal Types Module Interface --
@@ -25,6 +39,7 @@ pub use parser::EDLParser;
 
 use parser_types::{
     EDLSection,
+    EDLSectionOutcome,
     EDLField,
     EDLValue,
     EDLTrackEventColumn,
@@ -44,7 +59,13 @@ use parser_types::{
     EDL_FIELD_NAME_INDEX,
     EDL_FIELD_VALUE_INDEX,
     EDL_TRACK_EVENT_VALID_COLUMN_WIDTHS,
+    EDLTRACKEVENTCOLUMN_SIZE,
     EDLPARSER_MASK_SECTION_PLUGINSLISTING,
+    EDLPARSER_MASK_SECTION_ONLINEFILES,
+    EDLPARSER_MASK_SECTION_OFFLINEFILES,
+    EDLPARSER_MASK_SECTION_ONLINECLIPS,
+    EDLPARSER_MASK_SECTION_TRACKLISTING,
+    EDLPARSER_MASK_SECTION_MARKERSLISTING,
 };
 
 ///////////////////////////////////////////////////////////////////////////
@@ -66,8 +87,14 @@ pub use parser_traits::{
 
 pub use session::{
     EDLSession,
+    EDLSessionBuilder,
     EDLSESSION_FLAG_DEFAULT,
     EDLSESSION_FLAG_CONTAINS_PLUGIN,
+    EDLSESSION_FLAG_CONTAINS_ONLINE_FILES,
+    EDLSESSION_FLAG_CONTAINS_OFFLINE_FILES,
+    EDLSESSION_FLAG_CONTAINS_ONLINE_CLIPS,
+    EDLSESSION_FLAG_CONTAINS_TRACKS,
+    EDLSESSION_FLAG_CONTAINS_MARKERS,
 };
 
 ///////////////////////////////////////////////////////////////////////////
@@ -80,11 +107,17 @@ pub use session_types::{
     EDLClip,
     EDLFileList,
     EDLMarker,
+    EDLMarkerLocation,
     EDLMediaFile,
     EDLPlugin,
     EDLPluginFormat,
     EDLPluginInstance,
+    EDLSourceLine,
     EDLTrack,
+    EDLTrackDelay,
     EDLTrackEvent,
+    EDLTrackState,
     EDLUnit,
+    EDLEVENT_FLAG_DEFAULT,
+    EDLEVENT_FLAG_HAS_TIMESTAMP,
 };
@@ -5,13 +5,9 @@
 
 use std::borrow::Borrow;
 use std::cmp::Ordering;
-use std::io::{BufRead, BufReader};
-use std::fs::File;
 use std::str::FromStr;
 use std::{println, marker};
 
-use encoding_rs_io::DecodeReaderBytesBuilder;
-
 use crate::edl::protools::*;
 use crate::chrono::{
     Timecode,
@@ -28,13 +24,20 @@ use crate::format::{
 //
 ///////////////////////////////////////////////////////////////////////////
 
+/// Parses Protools EDLs into `EDLSession`s
+///
+/// The associated functions such as `EDLParser::parse` cover one-off
+/// parses. A parser configured with `EDLParser::new` can be reused across
+/// files, and keeps the warnings of the last file it parsed.
 #[derive(Debug, Default)]
-pub struct EDLParser<'a> {
-    file_path: &'a str,
+pub struct EDLParser {
     file_position: usize,
     section_position: usize,
     current_section: EDLSection,
+    warnings: Vec<EDLWarning>,
     flags: u8,
+    encoding: Option<&'static encoding_rs::Encoding>,
+    strict: bool,
 }
 
 ///////////////////////////////////////////////////////////////////////////
@@ -43,322 +46,1129 @@ pub struct EDLParser<'a> {
 //
 ///////////////////////////////////////////////////////////////////////////
 
-impl<'a> EDLParser<'a> {
-    pub fn parse(input_path: &'a str, encoding: &'static encoding_rs::Encoding) -> Result<EDLSession, String> {
-        let mut edl_parser = EDLParser {
-            file_path: input_path,
-            current_section: EDLSection::Header,
+impl EDLParser {
+    /// A parser that detects each file's encoding from its byte order mark,
+    /// falling back to UTF-8, and skips malformed table rows with a warning
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads files in `encoding` rather than detecting it, although a byte
+    /// order mark still takes precedence
+    pub fn encoding(mut self, encoding: &'static encoding_rs::Encoding) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// When `strict` is `true`, a table row with the wrong number of columns
+    /// fails the parse with `EDLError::ColumnCountMismatch` instead of being
+    /// skipped with `EDLWarning::SkippedRow`, and an unrecognized TIMECODE
+    /// FORMAT fails with `EDLError::UnknownFrameRate` instead of falling
+    /// back to an inferred frame rate
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Parses the Protools EDL file at `input_path` with this parser's
+    /// configuration
+    pub fn parse_path(&mut self, input_path: &str) -> Result<EDLSession, EDLError> {
+        let input_bytes = EDLParser::read_input(input_path)?;
+        let encoding = self.encoding.unwrap_or_else(|| {
+            encoding_rs::Encoding::for_bom(&input_bytes).map_or(encoding_rs::UTF_8, |(encoding, _)| encoding)
+        });
+
+        self.reset();
+        self.parse_decoded(&input_bytes, encoding)
+    }
+
+    /// Parses a Protools EDL that has already been decoded to text, with
+    /// this parser's configuration apart from its encoding
+    pub fn parse_str(&mut self, input_text: &str) -> Result<EDLSession, EDLError> {
+        self.reset();
+        self.parse_decoded(input_text.as_bytes(), encoding_rs::UTF_8)
+    }
+
+    /// The non-fatal issues found in the last file this parser parsed
+    pub fn warnings(&self) -> &[EDLWarning] {
+        &self.warnings
+    }
+
+    pub fn parse(input_path: &str, encoding: &'static encoding_rs::Encoding) -> Result<EDLSession, EDLError> {
+        EDLParser::parse_with_warnings(input_path, encoding).map(|(edl_session, _)| edl_session)
+    }
+
+    /// Parses a Protools EDL file, returning the session along with any
+    /// non-fatal issues found along the way, such as table rows that were
+    /// skipped for having the wrong number of columns
+    pub fn parse_with_warnings(input_path: &str, encoding: &'static encoding_rs::Encoding) -> Result<(EDLSession, Vec<EDLWarning>), EDLError> {
+        let mut edl_parser = EDLParser::new().encoding(encoding);
+        let edl_session = edl_parser.parse_path(input_path)?;
+        Ok((edl_session, edl_parser.warnings))
+    }
+
+    /// Parses each of `input_paths`, pairing every path with its own result
+    /// so that one file failing to parse does not stop the rest
+    ///
+    /// Results are in the same order as `input_paths`. With the `rayon`
+    /// feature enabled, files are parsed in parallel.
+    pub fn parse_many(input_paths: &[&str], encoding: &'static encoding_rs::Encoding) -> Vec<(String, Result<EDLSession, EDLError>)> {
+        let parse_path = |input_path: &&str| (input_path.to_string(), EDLParser::parse(input_path, encoding));
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            input_paths.par_iter().map(parse_path).collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            input_paths.iter().map(parse_path).collect()
+        }
+    }
+
+    /// Parses a Protools EDL file, detecting its encoding from a byte order
+    /// mark and falling back to UTF-8 when there is none
+    pub fn parse_auto(input_path: &str) -> Result<EDLSession, EDLError> {
+        EDLParser::new().parse_path(input_path)
+    }
+
+    /// Parses a Protools EDL from raw bytes in the given encoding, returning
+    /// the session along with any non-fatal issues found along the way
+    ///
+    /// A byte order mark at the start of the input is stripped, and takes
+    /// precedence over `encoding`. Byte sequences that are invalid for the
+    /// encoding are replaced rather than rejected, and reported as
+    /// `EDLWarning::MalformedEncoding`.
+    pub fn parse_bytes(bytes: &[u8], encoding: &'static encoding_rs::Encoding) -> Result<(EDLSession, Vec<EDLWarning>), EDLError> {
+        let mut edl_parser = EDLParser::default();
+        let edl_session = edl_parser.parse_decoded(bytes, encoding)?;
+        Ok((edl_session, edl_parser.warnings))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLParser` Private Implementation --
+//
+///////////////////////////////////////////////////////////////////////////
+
+impl EDLParser {
+    /// Clears the state left by a previous parse, keeping the configuration
+    fn reset(&mut self) {
+        *self = EDLParser {
+            encoding: self.encoding,
+            strict: self.strict,
             ..EDLParser::default()
         };
+    }
+
+    fn parse_decoded(&mut self, bytes: &[u8], encoding: &'static encoding_rs::Encoding) -> Result<EDLSession, EDLError> {
+        let (input_text, _, had_errors) = encoding.decode(bytes);
+        if had_errors {
+            self.warnings.push(EDLWarning::MalformedEncoding);
+        }
+
+        self.current_section = EDLSection::Header;
 
-        let input_file = File::open(input_path).map_err(|_| "could not open EDL file for parsing".to_string())?;
-        let input_file_decoder = DecodeReaderBytesBuilder::new()
-            .encoding(Some(encoding))
-            .build(input_file);
-        let input_file_handle = BufReader::new(input_file_decoder);
-        let mut all_lines = input_file_handle.lines();
-
-        let mut raw_header_lines = Vec::<(usize, String)>::with_capacity(EDL_HEADER_LINE_SIZE as usize);
-        let mut raw_tracks_listings_lines = Vec::<(usize, String)>::new();
-        let mut raw_markers_listings_lines = Vec::<(usize, String)>::new();
-        let mut raw_plugins_listings_lines = Vec::<(usize, String)>::new();
-        let mut raw_offline_files_lines = Vec::<(usize, String)>::new();
-        let mut raw_online_files_lines = Vec::<(usize, String)>::new();
-        let mut raw_online_clips_lines = Vec::<(usize, String)>::new();
+        let mut raw_header_lines = Vec::<(usize, &str)>::with_capacity(EDL_HEADER_LINE_SIZE as usize);
+        let mut raw_tracks_listings_lines = Vec::<(usize, &str)>::new();
+        let mut raw_markers_listings_lines = Vec::<(usize, &str)>::new();
+        let mut raw_plugins_listings_lines = Vec::<(usize, &str)>::new();
+        let mut raw_offline_files_lines = Vec::<(usize, &str)>::new();
+        let mut raw_online_files_lines = Vec::<(usize, &str)>::new();
+        let mut raw_online_clips_lines = Vec::<(usize, &str)>::new();
 
         let mut edl_session = EDLSession::new();
 
-        while let Some(line_result) = all_lines.next() {
-            let line = line_result.expect("line in EDL file handle should be parseable");
-            let trimmed_line = line.as_str().trim();
-            let mut skip = line.trim() == "";
-            edl_parser.file_position += 1;
+        for line in EDLParser::split_lines(&input_text) {
+            let trimmed_line = line.trim();
+            let mut skip = line.trim().is_empty();
+            self.file_position += 1;
 
             use EDLSection::*;
-            if edl_parser.is_section_declaration(trimmed_line) {
-                edl_parser.current_section =
+            if self.is_section_declaration(trimmed_line) {
+                self.current_section =
                     if trimmed_line == PluginsListing.section_name() { skip = true; PluginsListing }
                     else if trimmed_line == TrackListing.section_name() { skip = true; TrackListing }
                     else if trimmed_line == MarkersListing.section_name() { skip = true; MarkersListing }
                     else if trimmed_line == OfflineFiles.section_name() { skip = true; OfflineFiles }
                     else if trimmed_line == OnlineFiles.section_name() { skip = true; OnlineFiles }
                     else if trimmed_line == OnlineClips.section_name() { skip = true; OnlineClips }
-                    else { Unknown };
+                    else {
+                        self.warnings.push(EDLWarning::UnknownSection { line: self.file_position, value: trimmed_line.to_string() });
+                        edl_session.unknown_sections.push((trimmed_line.to_string(), Vec::<String>::new()));
+                        skip = true;
+                        Unknown
+                    };
+
+                self.flags |= self.current_section.parser_mask();
             }
 
             if skip { continue; }
 
-            match edl_parser.current_section {
+            match self.current_section {
                 Header => {
-                    raw_header_lines.push((edl_parser.file_position, line.to_string()));
+                    raw_header_lines.push((self.file_position, line));
                 },
 
                 PluginsListing => {
-                    // TODO: Set EDLParser flags for plugins listing
-                    raw_plugins_listings_lines.push((edl_parser.file_position, line.to_string()));
-                    edl_session.set_flag(EDLSESSION_FLAG_CONTAINS_PLUGIN);
+                    raw_plugins_listings_lines.push((self.file_position, line));
                 },
 
                 OnlineFiles => {
-                    raw_online_files_lines.push((edl_parser.file_position, line.to_string()));
+                    raw_online_files_lines.push((self.file_position, line));
                 },
 
                 OfflineFiles => {
-                    raw_offline_files_lines.push((edl_parser.file_position, line.to_string()));
+                    raw_offline_files_lines.push((self.file_position, line));
                 },
 
                 OnlineClips => {
-                    raw_online_clips_lines.push((edl_parser.file_position, line.to_string()));
+                    raw_online_clips_lines.push((self.file_position, line));
                 },
 
                 TrackListing => {
-                    raw_tracks_listings_lines.push((edl_parser.file_position, line.to_string()));
+                    raw_tracks_listings_lines.push((self.file_position, line));
                 },
 
                 MarkersListing => {
-                    raw_markers_listings_lines.push((edl_parser.file_position, line.to_string()));
+                    raw_markers_listings_lines.push((self.file_position, line));
                 },
 
-                Unknown => { /* TODO: Report? */ }
+                Unknown => {
+                    if let Some((_, unknown_section_lines)) = edl_session.unknown_sections.last_mut() {
+                        unknown_section_lines.push(line.to_string());
+                    }
+                },
             }
         }
 
-        if let Some(_) = edl_parser.parse_header(&mut raw_header_lines, &mut edl_session) {
-        }
+        self.parse_header(&raw_header_lines, &raw_tracks_listings_lines, &mut edl_session)?;
 
-        if let Some(_) = edl_parser.parse_plugins_listing(&mut raw_plugins_listings_lines, &mut edl_session) {
+        // Unlike the other sections, the plugin flag means the session has
+        // plugins, so a plugins banner without any plugin rows leaves it unset
+        if let EDLSectionOutcome::Parsed(_) = self.parse_plugins_listing(&raw_plugins_listings_lines, &mut edl_session)? {
+            edl_session.set_flag(EDLSESSION_FLAG_CONTAINS_PLUGIN);
         }
 
-        if let Some(_) = edl_parser.parse_offline_files_listing(&mut raw_offline_files_lines, &mut edl_session) {
-        }
+        let section_outcomes = [
+            (EDLSESSION_FLAG_CONTAINS_OFFLINE_FILES, self.parse_offline_files_listing(&raw_offline_files_lines, &mut edl_session)?),
+            (EDLSESSION_FLAG_CONTAINS_ONLINE_FILES, self.parse_online_files_listing(&raw_online_files_lines, &mut edl_session)?),
+            (EDLSESSION_FLAG_CONTAINS_ONLINE_CLIPS, self.parse_online_clips_listing(&raw_online_clips_lines, &mut edl_session)?),
+            (EDLSESSION_FLAG_CONTAINS_TRACKS, self.parse_tracks_listing(&raw_tracks_listings_lines, &mut edl_session)?),
+            (EDLSESSION_FLAG_CONTAINS_MARKERS, self.parse_markers_listing(&raw_markers_listings_lines, &mut edl_session)?),
+        ];
 
-        if let Some(_) = edl_parser.parse_online_files_listing(&mut raw_online_files_lines, &mut edl_session) {
+        for (flag, outcome) in section_outcomes {
+            if outcome != EDLSectionOutcome::Absent {
+                edl_session.set_flag(flag);
+            }
         }
 
-        if let Some(_) = edl_parser.parse_online_clips_listing(&mut raw_online_clips_lines, &mut edl_session) {
-        }
+        Ok(edl_session)
+    }
 
-        if let Some(_) = edl_parser.parse_tracks_listing(&mut raw_tracks_listings_lines, &mut edl_session) {
-        }
+    fn parse_header(&mut self, raw_header_lines: &[(usize, &str)], raw_tracks_listings_lines: &[(usize, &str)], edl_session: &mut EDLSession) -> Result<(), EDLError> {
+        let mut start_timecode_field = None::<(usize, &str)>;
+        let mut timecode_format_field = None::<(usize, &str)>;
 
-        if let Some(_) = edl_parser.parse_markers_listing(&mut raw_markers_listings_lines, &mut edl_session) {
-        }
+        for (line_number, line) in raw_header_lines {
+            let line_number = *line_number;
+            let (field_name, field_value) = match EDLParser::parse_edl_field(line) {
+                Some(EDLValue::Field(field_name, field_value)) => (field_name, field_value),
 
-        Ok(edl_session)
-    }
-    
-    // TODO: Proper errors for parse_* functions
-    fn parse_header(&self, raw_header_lines: &mut Vec<(usize, String)>, edl_session: &mut EDLSession) -> Option<()> {
-        for field in raw_header_lines {
-            if let Ok(EDLValue::Field(field_name, field_value)) = EDLParser::parse_edl_field(field.1.as_str()) {
-                if field_name == EDLField::SessionName { edl_session.name = field_value.to_string(); }
-                else if field_name == EDLField::SessionSampleRate { edl_session.sample_rate = SampleRate::parse_field(field_value).expect("EDL header sample rate field should have a valid floating point value") }
-                else if field_name == EDLField::SessionBitDepth { edl_session.bit_depth = BitDepth::parse_field(field_value).expect("EDL header bit depth field should have a valid bit depth option value") }
-                else if field_name == EDLField::SessionStartTimecode { edl_session.start_timecode = Timecode::from_str(field_value, edl_session.fps).expect("EDL header start timecode field should have a valid timecode string"); }
-                else if field_name == EDLField::SessionTimecodeFormat {
-                    let fps = FrameRate::parse_field(field_value).expect("EDL header timecode format field should have a valid fps string");
-                    edl_session.start_timecode.set_frame_rate(fps);
-                    edl_session.fps = fps;
-                }
-                else if field_name == EDLField::SessionNumAudioTracks { edl_session.num_audio_tracks = field_value.parse::<u32>().expect("EDL header number audio tracks field should have a valid integer number value"); }
-                else if field_name == EDLField::SessionNumAudioClips { edl_session.num_audio_clips = field_value.parse::<u32>().expect("EDL header number audio clips field should have a valid integer number value"); }
-                else if field_name == EDLField::SessionNumAudioFiles { edl_session.num_audio_files = field_value.parse::<u32>().expect("EDL header number audio files field should have a valid integer number value"); }
-                else { panic!("unexpected field name in EDL header section"); }
-            } else {
-                return Some(())
+                // Newer Protools versions add header fields, which are kept
+                // rather than failing the parse
+                None => match line.split_once(':') {
+                    Some((field_name, field_value)) => {
+                        edl_session.extra_header.push((field_name.trim().to_string(), field_value.trim().to_string()));
+                        continue;
+                    },
+                    None => return Err(EDLError::UnexpectedField { line: line_number, field: line.trim().to_string() }),
+                },
+            };
+
+            if field_name == EDLField::SessionName { edl_session.name = field_value.to_string(); }
+            else if field_name == EDLField::SessionSampleRate {
+                edl_session.sample_rate = SampleRate::parse_field(field_value)
+                    .ok_or_else(|| EDLError::UnknownSampleRate { line: line_number, value: field_value.trim().to_string() })?;
+            }
+            else if field_name == EDLField::SessionBitDepth {
+                edl_session.bit_depth = BitDepth::parse_field(field_value)
+                    .ok_or_else(|| EDLError::UnknownBitDepth { line: line_number, value: field_value.trim().to_string() })?;
             }
+            else if field_name == EDLField::SessionStartTimecode { start_timecode_field = Some((line_number, field_value.trim())); }
+            else if field_name == EDLField::SessionTimecodeFormat { timecode_format_field = Some((line_number, field_value.trim())); }
+            else if field_name == EDLField::SessionNumAudioTracks { edl_session.num_audio_tracks = EDLParser::parse_field_number(field_value, line_number)?; }
+            else if field_name == EDLField::SessionNumAudioClips { edl_session.num_audio_clips = EDLParser::parse_field_number(field_value, line_number)?; }
+            else if field_name == EDLField::SessionNumAudioFiles { edl_session.num_audio_files = EDLParser::parse_field_number(field_value, line_number)?; }
+            else { return Err(EDLError::UnexpectedField { line: line_number, field: field_name.field_name().to_string() }); }
         }
 
-        None
+        // Without a usable TIMECODE FORMAT every timecode would be read at
+        // the default frame rate, so the rate is inferred from the
+        // delimiters of the session's timecodes instead, with a warning
+        edl_session.fps = match timecode_format_field.map(|(line_number, fps_string)| (line_number, fps_string, FrameRate::parse_field(fps_string))) {
+            Some((_, _, Some(fps))) => fps,
+            Some((line_number, fps_string, None)) if self.strict => {
+                return Err(EDLError::UnknownFrameRate { line: line_number, value: fps_string.to_string() });
+            },
+            unusable_field => {
+                let timecode_lines = start_timecode_field.iter().chain(raw_tracks_listings_lines.iter());
+                let fallback = EDLParser::infer_frame_rate(timecode_lines.map(|&(_, line)| line));
+
+                self.warnings.push(match unusable_field {
+                    Some((line_number, fps_string, _)) => EDLWarning::UnknownFrameRate { line: line_number, value: fps_string.to_string(), fallback },
+                    None => EDLWarning::MissingFrameRate { fallback },
+                });
+
+                fallback
+            },
+        };
+
+        // The start timecode is parsed once the whole header has been read,
+        // since the TIMECODE FORMAT field may come after it
+        edl_session.start_timecode = match start_timecode_field {
+            Some((line_number, tc_string)) => Timecode::from_str(tc_string, edl_session.fps)
+                .map_err(|source| EDLError::InvalidTimecode { line: line_number, column: 2, value: tc_string.to_string(), source })?,
+            None => Timecode::with_fps(edl_session.fps),
+        };
+
+        Ok(())
     }
 
-    fn parse_plugins_listing(&self, raw_plugins_listings_lines: &mut Vec<(usize, String)>, edl_session: &mut EDLSession) -> Option<()> {
-        if let Some(plugins_list) = EDLPlugin::parse_table(
-            raw_plugins_listings_lines
-                .as_slice()
-                .into_iter()
-                .map(|(_, v)| v.clone())
-                .collect::<Vec<_>>()
-                .as_slice(),
+    fn parse_plugins_listing(&mut self, raw_plugins_listings_lines: &[(usize, &str)], edl_session: &mut EDLSession) -> Result<EDLSectionOutcome, EDLError> {
+        edl_session.plugins = EDLPlugin::parse_table(
+            self.table_lines::<EDLPlugin, ()>(raw_plugins_listings_lines)?.as_slice(),
             ()
-        ) {
-            edl_session.plugins = plugins_list;
-        }
-
-        else {
-            return Some(());
-        }
+        ).map_err(|error| EDLParser::table_error_at_file_line(error, raw_plugins_listings_lines))?;
 
-        None
+        Ok(self.section_outcome(EDLSection::PluginsListing, edl_session.plugins.len()))
     }
 
-    fn parse_tracks_listing(&self, raw_tracks_listings_lines: &mut Vec<(usize, String)>, edl_session: &mut EDLSession) -> Option<()> {
+    fn parse_tracks_listing(&mut self, raw_tracks_listings_lines: &[(usize, &str)], edl_session: &mut EDLSession) -> Result<EDLSectionOutcome, EDLError> {
         let mut i = 0;
+        let mut track_lines = Vec::<usize>::new();
 
-        // Assumes that plugins listing has already been parsed
-        let track_header_size =
-            if edl_session.check_flag(EDLSESSION_FLAG_CONTAINS_PLUGIN) {
-                4
-            } else {
-                3
-            };
-
-        while i < raw_tracks_listings_lines.len() && i + track_header_size < raw_tracks_listings_lines.len() {
+        while i < raw_tracks_listings_lines.len() {
             const TRACK_START_STRING: &str = "TRACK NAME:";
-            let next_track_index =
-                if let Some(next_track_index) = raw_tracks_listings_lines.iter().enumerate().position(|v| {
-                    if v.0 > i && v.1.1.len() >= TRACK_START_STRING.len() && &v.1.1[..11] == TRACK_START_STRING { true }
-                    else { false }
-                }) {
-                    next_track_index
-                }
-
-                else {
-                    raw_tracks_listings_lines.len()
-                };
+            let next_track_index = raw_tracks_listings_lines
+                .iter()
+                .enumerate()
+                .position(|(j, (_, line))| j > i && line.starts_with(TRACK_START_STRING))
+                .unwrap_or(raw_tracks_listings_lines.len());
 
-                if i < raw_tracks_listings_lines.len() {
-                    let mut track = EDLTrack::default();
+            // The track's field lines run up to the first line that is not a
+            // track field, which is the header row of the events table
+            let events_start_index = raw_tracks_listings_lines[i..next_track_index]
+                .iter()
+                .position(|(_, line)| !EDLParser::is_track_field(line))
+                .map_or(next_track_index, |position| i + position);
 
-                    for (_, (_, line)) in raw_tracks_listings_lines[i..i + track_header_size].iter().enumerate() {
-                        if let Ok(EDLValue::Field(field_name, field_value)) = EDLParser::parse_edl_field(line.as_str()) {
-                            if field_name == EDLField::TrackName { track.name = field_value.trim().to_string() }
-                            else if field_name == EDLField::TrackComment { track.comment = field_value.to_string(); }
-                            else if field_name == EDLField::TrackDelay { track.delay = field_value.split(" ").collect::<Vec<_>>()[0].parse::<u32>().expect("EDLTrack field delay should be a valid number"); }
-                            else if field_name == EDLField::TrackState { /* TODO: Handle track states */ }
-                            else if field_name == EDLField::TrackPlugins { track.plugins = field_value.split("\t").map(|v| v.trim().to_string()).collect::<Vec<_>>(); }
-                            else { panic!("unexpected field name in EDL header section"); }
-                        }
-
-                        else {
-                            // TODO: Report?
-                        }
+            let mut track = EDLTrack::default();
+            let track_line_number = raw_tracks_listings_lines[i].0;
 
+            for (line_number, line) in raw_tracks_listings_lines[i..events_start_index].iter() {
+                let line_number = *line_number;
+                if let Some(EDLValue::Field(field_name, field_value)) = EDLParser::parse_edl_field(line) {
+                    if field_name == EDLField::TrackName { track.name = field_value.trim().to_string() }
+                    else if field_name == EDLField::TrackComment { track.comment = field_value.to_string(); }
+                    else if field_name == EDLField::TrackDelay {
+                        let (amount, unit) = field_value.trim().split_once(' ').unwrap_or((field_value.trim(), ""));
+                        track.delay = EDLTrackDelay {
+                            amount: amount.parse::<i64>()
+                                .map_err(|_| EDLError::InvalidNumber { line: line_number, column: 2, value: amount.to_string() })?,
+                            unit: if unit.trim().is_empty() { EDLUnit::Samples } else {
+                                EDLUnit::parse_field(unit).ok_or_else(|| EDLError::UnknownUnit { line: line_number, column: 2, value: unit.trim().to_string() })?
+                            },
+                        };
                     }
+                    else if field_name == EDLField::TrackState { track.state = EDLTrackState::from_str(field_value); }
+                    else if field_name == EDLField::TrackPlugins { track.plugins = field_value.split('\t').map(|v| v.trim().to_string()).filter(|v| !v.is_empty()).collect::<Vec<_>>(); }
+                    else { return Err(EDLError::UnexpectedField { line: line_number, field: field_name.field_name().to_string() }); }
+                }
+            }
 
-                    if let Some(events) = EDLTrackEvent::parse_table(
-                        raw_tracks_listings_lines[i + track_header_size + 1..next_track_index]
-                            .into_iter()
-                            .map(|(_, v)| v.clone())
-                            .collect::<Vec<_>>()
-                            .as_slice(),
-                        edl_session.fps
-                    ) {
-                        track.events = events;
-                    }
+            let raw_events_lines = &raw_tracks_listings_lines[events_start_index..next_track_index];
+            track.events = EDLTrackEvent::parse_table(
+                self.table_lines::<EDLTrackEvent, FrameRate>(raw_events_lines)?.as_slice(),
+                edl_session.fps
+            ).map_err(|error| EDLParser::table_error_at_file_line(error, raw_events_lines))?;
 
-                    edl_session.tracks.push(track);
+            for (line_number, event) in EDLParser::table_row_line_numbers::<EDLTrackEvent, FrameRate>(raw_events_lines).zip(track.events.iter()) {
+                if !event.has_consistent_duration() {
+                    self.warnings.push(EDLWarning::DurationMismatch { line: line_number, duration: event.duration, span: event.time_out - event.time_in });
                 }
+            }
+
+            track_lines.push(track_line_number);
+            edl_session.tracks.push(track);
 
-                i = next_track_index;
+            i = next_track_index;
         }
 
+        // Protools allows tracks to share a name, which `EDLSession::track`
+        // cannot tell apart, so each shared name is reported once with the
+        // lines of every track that uses it
+        for (j, track) in edl_session.tracks.iter().enumerate() {
+            if edl_session.tracks[..j].iter().any(|earlier_track| earlier_track.name == track.name) {
+                continue;
+            }
+
+            let lines = edl_session.tracks.iter()
+                .zip(track_lines.iter())
+                .filter(|(other_track, _)| other_track.name == track.name)
+                .map(|(_, &line_number)| line_number)
+                .collect::<Vec<_>>();
+
+            if lines.len() > 1 {
+                self.warnings.push(EDLWarning::DuplicateTrackName { lines, name: track.name.clone() });
+            }
+        }
 
-        None
+        Ok(self.section_outcome(EDLSection::TrackListing, edl_session.tracks.len()))
     }
 
-    fn parse_markers_listing(&self, raw_markers_listings_lines: &mut Vec<(usize, String)>, edl_session: &mut EDLSession) -> Option<()> {
-        if let Some(markers_listing) = EDLMarker::parse_table(
-            raw_markers_listings_lines
-                .as_slice()
-                .into_iter()
-                .map(|(_, v)| v.clone())
-                .collect::<Vec<_>>()
-                .as_slice(),
+    fn parse_markers_listing(&mut self, raw_markers_listings_lines: &[(usize, &str)], edl_session: &mut EDLSession) -> Result<EDLSectionOutcome, EDLError> {
+        edl_session.markers = EDLMarker::parse_table(
+            self.table_lines::<EDLMarker, FrameRate>(raw_markers_listings_lines)?.as_slice(),
             edl_session.fps
-        ) {
-            edl_session.markers = markers_listing;
+        ).map_err(|error| EDLParser::table_error_at_file_line(error, raw_markers_listings_lines))?;
+
+        for (line_number, marker) in EDLParser::table_row_line_numbers::<EDLMarker, FrameRate>(raw_markers_listings_lines).zip(edl_session.markers.iter_mut()) {
+            marker.source_line = EDLSourceLine(Some(line_number));
         }
 
-        else {
-            return Some(());
+        for marker in edl_session.markers.iter() {
+            if let (false, EDLMarkerLocation::Timecode(location), Some(line_number)) = (edl_session.has_consistent_marker_reference(marker), &marker.location, marker.source_line.0) {
+                self.warnings.push(EDLWarning::MarkerReferenceMismatch { line: line_number, location: *location, time_reference: marker.time_reference });
+            }
         }
 
-        None
+        // Gaps in marker ids are normal after edits, but a repeated id makes
+        // `EDLSession::marker` ambiguous, so each repeat is reported once
+        // with the lines of every marker that uses it
+        for (i, marker) in edl_session.markers.iter().enumerate() {
+            if edl_session.markers[..i].iter().any(|earlier_marker| earlier_marker.id == marker.id) {
+                continue;
+            }
+
+            let lines = edl_session.markers.iter()
+                .filter(|other_marker| other_marker.id == marker.id)
+                .filter_map(|other_marker| other_marker.source_line.0)
+                .collect::<Vec<_>>();
+
+            if lines.len() > 1 {
+                self.warnings.push(EDLWarning::DuplicateMarkerId { lines, id: marker.id });
+            }
+        }
+
+        Ok(self.section_outcome(EDLSection::MarkersListing, edl_session.markers.len()))
     }
 
-    fn parse_online_files_listing(&self, raw_online_files_lines: &mut Vec<(usize, String)>, edl_session: &mut EDLSession) -> Option<()> {
-        if let Some(online_files) = EDLMediaFile::parse_table(
-            raw_online_files_lines
-                .as_slice()
-                .into_iter()
-                .map(|(_, v)| v.clone())
-                .collect::<Vec<_>>()
-                .as_slice(),
+    fn parse_online_files_listing(&mut self, raw_online_files_lines: &[(usize, &str)], edl_session: &mut EDLSession) -> Result<EDLSectionOutcome, EDLError> {
+        edl_session.files.online_files = EDLMediaFile::parse_table(
+            self.table_lines::<EDLMediaFile, ()>(raw_online_files_lines)?.as_slice(),
             ()
-        ) {
-            edl_session.files.online_files = online_files;
-        }
+        ).map_err(|error| EDLParser::table_error_at_file_line(error, raw_online_files_lines))?;
 
-        else {
-            return Some(());
+        for (line_number, media_file) in EDLParser::table_row_line_numbers::<EDLMediaFile, ()>(raw_online_files_lines).zip(edl_session.files.online_files.iter_mut()) {
+            media_file.source_line = EDLSourceLine(Some(line_number));
+            if media_file.location.is_none() {
+                self.warnings.push(EDLWarning::MissingFileLocation { line: line_number, file_name: media_file.file_name.clone() });
+            }
         }
 
-        None
+        Ok(self.section_outcome(EDLSection::OnlineFiles, edl_session.files.online_files.len()))
     }
 
-    fn parse_offline_files_listing(&self, raw_offline_files_lines: &mut Vec<(usize, String)>, edl_session: &mut EDLSession) -> Option<()> {
-        if let Some(offline_files) = EDLMediaFile::parse_table(
-            raw_offline_files_lines
-                .as_slice()
-                .into_iter()
-                .map(|(_, v)| v.clone())
-                .collect::<Vec<_>>()
-                .as_slice(),
+    fn parse_offline_files_listing(&mut self, raw_offline_files_lines: &[(usize, &str)], edl_session: &mut EDLSession) -> Result<EDLSectionOutcome, EDLError> {
+        edl_session.files.offline_files = EDLMediaFile::parse_table(
+            self.table_lines::<EDLMediaFile, ()>(raw_offline_files_lines)?.as_slice(),
             ()
-        ) {
-            edl_session.files.offline_files = offline_files;
-        }
+        ).map_err(|error| EDLParser::table_error_at_file_line(error, raw_offline_files_lines))?;
 
-        else {
-            return Some(());
+        for (line_number, media_file) in EDLParser::table_row_line_numbers::<EDLMediaFile, ()>(raw_offline_files_lines).zip(edl_session.files.offline_files.iter_mut()) {
+            media_file.source_line = EDLSourceLine(Some(line_number));
         }
 
-        None
+        Ok(self.section_outcome(EDLSection::OfflineFiles, edl_session.files.offline_files.len()))
     }
 
-    fn parse_online_clips_listing(&self, raw_online_clips_lines: &mut Vec<(usize, String)>, edl_session: &mut EDLSession) -> Option<()> {
-        if let Some(online_clips) = EDLClip::parse_table(
-            raw_online_clips_lines
-                .as_slice()
-                .into_iter()
-                .map(|(_, v)| v.clone())
-                .collect::<Vec<_>>()
-                .as_slice(),
+    fn parse_online_clips_listing(&mut self, raw_online_clips_lines: &[(usize, &str)], edl_session: &mut EDLSession) -> Result<EDLSectionOutcome, EDLError> {
+        edl_session.files.online_clips = EDLClip::parse_table(
+            self.table_lines::<EDLClip, ()>(raw_online_clips_lines)?.as_slice(),
             ()
-        ) {
-            edl_session.files.online_clips = online_clips;
+        ).map_err(|error| EDLParser::table_error_at_file_line(error, raw_online_clips_lines))?;
+
+        for (line_number, clip) in EDLParser::table_row_line_numbers::<EDLClip, ()>(raw_online_clips_lines).zip(edl_session.files.online_clips.iter_mut()) {
+            clip.source_line = EDLSourceLine(Some(line_number));
         }
 
-        else {
-            return Some(());
+        Ok(self.section_outcome(EDLSection::OnlineClips, edl_session.files.online_clips.len()))
+    }
+
+    /// Whether `section` was absent from the input, present without rows,
+    /// or present with `total_rows` rows
+    fn section_outcome(&self, section: EDLSection, total_rows: usize) -> EDLSectionOutcome {
+        if self.flags & section.parser_mask() == 0 { EDLSectionOutcome::Absent }
+        else if total_rows == 0 { EDLSectionOutcome::Empty }
+        else { EDLSectionOutcome::Parsed(total_rows) }
+    }
+
+    /// Reads the raw bytes of the EDL file at `input_path`, naming the path
+    /// and the underlying cause if it cannot be read
+    fn read_input(input_path: &str) -> Result<Vec<u8>, EDLError> {
+        std::fs::read(input_path).map_err(|error| EDLError::Io(format!("'{}': {}", input_path, error)))
+    }
+
+    /// Collects the lines of a table for `T::parse_table`, reporting the
+    /// rows below the header row that it will skip, or failing on the first
+    /// of them in strict mode
+    fn table_lines<'l, T: ParseTable<T, D>, D>(&mut self, raw_table_lines: &[(usize, &'l str)]) -> Result<Vec<&'l str>, EDLError> {
+        let header_row = raw_table_lines.first().map_or("", |&(_, line)| line);
+        for (line_number, line) in raw_table_lines.iter().skip(1) {
+            if T::is_table_row(header_row, line) {
+                continue;
+            }
+
+            if self.strict {
+                return Err(EDLError::ColumnCountMismatch {
+                    line: *line_number,
+                    expected: T::expected_columns(header_row),
+                    found: line.split('\t').count(),
+                });
+            }
+
+            self.warnings.push(EDLWarning::SkippedRow { line: *line_number });
         }
 
-        None
+        Ok(raw_table_lines
+            .iter()
+            .map(|&(_, line)| line)
+            .collect::<Vec<_>>())
+    }
+
+    /// The lines of the input file that `T::parse_table` reads as rows, in
+    /// the order of the values it returns
+    fn table_row_line_numbers<'l, T: ParseTable<T, D>, D>(raw_table_lines: &'l [(usize, &str)]) -> impl Iterator<Item = usize> + 'l {
+        let header_row = raw_table_lines.first().map_or("", |&(_, line)| line);
+        raw_table_lines
+            .iter()
+            .skip(1)
+            .filter(move |(_, line)| T::is_table_row(header_row, line))
+            .map(|&(line_number, _)| line_number)
+    }
+
+    /// Converts the table relative line of an error from `ParseTable` into
+    /// the line of the input file it came from
+    fn table_error_at_file_line(error: EDLError, raw_table_lines: &[(usize, &str)]) -> EDLError {
+        error.map_line(|line| raw_table_lines.get(line.wrapping_sub(1)).map_or(line, |&(line_number, _)| line_number))
+    }
+
+    fn is_track_field(field_string: &str) -> bool {
+        use EDLField::*;
+        matches!(
+            EDLParser::parse_edl_field(field_string),
+            Some(EDLValue::Field(TrackName | TrackComment | TrackDelay | TrackState | TrackPlugins, _))
+        )
+    }
+
+    /// The frame rate to read timecodes at when the header does not give
+    /// one: 29.97 drop-frame if any tab separated field of `lines` is a
+    /// `;` delimited timecode, or else the default frame rate
+    fn infer_frame_rate<'l>(mut lines: impl Iterator<Item = &'l str>) -> FrameRate {
+        let dropframe_fps = FrameRate::Fps30(true);
+        let has_dropframe_timecode = lines.any(|line| {
+            line.split('\t').any(|field| Timecode::from_str(field.trim(), dropframe_fps).is_ok_and(|timecode| timecode.is_drop_frame()))
+        });
+
+        if has_dropframe_timecode { dropframe_fps } else { FrameRate::default() }
+    }
+
+    fn parse_field_number(field_value: &str, line_number: usize) -> Result<u32, EDLError> {
+        field_value.trim().parse::<u32>()
+            .map_err(|_| EDLError::InvalidNumber { line: line_number, column: 2, value: field_value.trim().to_string() })
+    }
+
+    /// Splits `text` into lines ending in `\n`, `\r\n`, or a lone `\r`, so
+    /// files with mixed line endings keep their line boundaries
+    fn split_lines(text: &str) -> impl Iterator<Item = &str> {
+        text.split('\n')
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .flat_map(|line| line.split('\r'))
     }
 
     fn is_section_declaration(&self, section_string: &str) -> bool {
         let all_parts = section_string
+            .trim()
             .split(' ')
-            .filter(|&c| c != "");
+            .filter(|&c| !c.is_empty());
 
         for part in all_parts {
             if part.len() != 1 { return false; }
         }
-        if section_string.trim() == "" { return false; }
+        if section_string.trim().is_empty() { return false; }
         true
     }
 
-    // TODO: Proper error for this function
-    fn parse_edl_field<'z>(field_string: &'z str) -> Result<EDLValue<'z>, String> {
-        let field_parts = field_string.split(":\t").into_iter().collect::<Vec<&str>>();
-        if field_parts.len() == 2 {
-            for field_variant in EDLField::all_variants() {
-                if field_variant.field_name() == field_parts[EDL_FIELD_NAME_INDEX] {
-                    return Ok(EDLValue::Field(*field_variant, field_parts[EDL_FIELD_VALUE_INDEX]));
-                }
-            }
+    fn parse_edl_field(field_string: &str) -> Option<EDLValue<'_>> {
+        let (field_name, field_value) = field_string.split_once(':')?;
+        let field_value = field_value.strip_prefix(['\t', ' ']).unwrap_or(field_value);
+
+        EDLField::all_variants()
+            .iter()
+            .find(|field_variant| field_variant.field_name() == field_name)
+            .map(|field_variant| EDLValue::Field(*field_variant, field_value))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLParser` Unit Tests --
+//
+///////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE_BASIC_SESSION: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/basic_session.txt");
+    const FIXTURE_CORRUPT_SAMPLE_RATE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/corrupt_sample_rate.txt");
+    const FIXTURE_TRACK_WITHOUT_COMMENT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/track_without_comment.txt");
+    const FIXTURE_BASIC_SESSION_UTF16LE_BOM: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/basic_session_utf16le_bom.txt");
+    const FIXTURE_BASIC_SESSION_UTF16BE_BOM: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/basic_session_utf16be_bom.txt");
+    const FIXTURE_BASIC_SESSION_CRLF: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/basic_session_crlf.txt");
+    const FIXTURE_CORRUPT_TIMECODE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/corrupt_timecode.txt");
+    const FIXTURE_MIXED_DROP_FRAME: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/mixed_drop_frame.txt");
+    const FIXTURE_TRACK_EVENTS_6_COLUMNS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/track_events_6_columns.txt");
+    const FIXTURE_TRACK_EVENTS_7_COLUMNS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/track_events_7_columns.txt");
+    const FIXTURE_TRACK_EVENTS_8_COLUMNS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/track_events_8_columns.txt");
+    const FIXTURE_TRACK_EVENTS_7_COLUMNS_TIMESTAMP: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/track_events_7_columns_timestamp.txt");
+    const FIXTURE_ONLINE_FILE_WITHOUT_LOCATION: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/online_file_without_location.txt");
+    const FIXTURE_OFFLINE_FILE_WITHOUT_LOCATION: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/offline_file_without_location.txt");
+    const FIXTURE_START_TIMECODE_BEFORE_FORMAT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/start_timecode_before_format.txt");
+    const FIXTURE_START_TIMECODE_AFTER_FORMAT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/start_timecode_after_format.txt");
+    const FIXTURE_DUPLICATE_TRACK_NAMES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/duplicate_track_names.txt");
+    const FIXTURE_TRACK_DELAYS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/track_delays.txt");
+    const FIXTURE_SHORT_MARKER_ROW: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/short_marker_row.txt");
+    const FIXTURE_TRACK_PLUGINS_MIXED: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/track_plugins_mixed.txt");
+    const FIXTURE_CLIP_NAME_WITH_TABS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/clip_name_with_tabs.txt");
+    const FIXTURE_MISSING_TIMECODE_FORMAT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/missing_timecode_format.txt");
+    const FIXTURE_EXTRA_HEADER_FIELDS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/extra_header_fields.txt");
+    const FIXTURE_EMPTY_PLUGINS_LISTING: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/empty_plugins_listing.txt");
+    const FIXTURE_UNKNOWN_SECTIONS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/unknown_sections.txt");
+    const FIXTURE_DUPLICATE_MARKER_IDS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/duplicate_marker_ids.txt");
+    const FIXTURE_MARKER_REFERENCE_CONSISTENT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/marker_reference_consistent.txt");
+    const FIXTURE_MARKER_REFERENCE_MISMATCH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/marker_reference_mismatch.txt");
+
+    #[test]
+    fn parse_basic_session() {
+        let session = EDLParser::parse(FIXTURE_BASIC_SESSION, encoding_rs::UTF_8).expect("basic session fixture should parse");
+
+        assert_eq!(session.name, "Timeline Kit Demo");
+        assert_eq!(session.sample_rate, SampleRate::Khz48);
+        assert_eq!(session.bit_depth, BitDepth::Bit24);
+        assert_eq!(session.fps, FrameRate::Fps25);
+        assert_eq!(session.plugins.len(), 1);
+        assert_eq!(session.files.online_files.len(), 2);
+        assert_eq!(session.files.offline_files.len(), 1);
+        assert_eq!(session.files.online_clips.len(), 3);
+        assert_eq!(session.tracks.len(), 2);
+        assert_eq!(session.tracks[0].events.len(), 2);
+        assert_eq!(session.tracks[1].events.len(), 2);
+        assert_eq!(session.tracks[1].state, EDLTrackState { muted: true, solo: true, ..EDLTrackState::default() });
+        assert_eq!(session.markers.len(), 2);
+    }
+
+    #[test]
+    fn parse_corrupt_sample_rate() {
+        let result = EDLParser::parse(FIXTURE_CORRUPT_SAMPLE_RATE, encoding_rs::UTF_8);
+        assert_eq!(result.unwrap_err(), EDLError::UnknownSampleRate { line: 2, value: "48k".to_string() });
+    }
+
+    #[test]
+    fn parse_unusual_sample_rate() {
+        let text = std::fs::read_to_string(FIXTURE_BASIC_SESSION).expect("basic session fixture should be readable");
+        let pull_down_text = text.replacen("SAMPLE RATE:\t48000.000000", "SAMPLE RATE:\t47952.000000", 1);
+
+        let (session, _) = EDLParser::parse_bytes(pull_down_text.as_bytes(), encoding_rs::UTF_8).expect("a pull-down sample rate should parse");
+        assert!(matches!(session.sample_rate, SampleRate::Other(hz) if hz == 47952.0));
+        assert!(session.to_edl_string().contains("SAMPLE RATE:\t47952.000000"));
+    }
+
+    #[test]
+    fn parsed_sessions_equal() {
+        let session = EDLParser::parse(FIXTURE_BASIC_SESSION, encoding_rs::UTF_8).expect("basic session fixture should parse");
+        let mut other_session = EDLParser::parse(FIXTURE_BASIC_SESSION_CRLF, encoding_rs::UTF_8).expect("basic session CRLF fixture should parse");
+        assert_eq!(session, other_session);
+
+        other_session.reset_flag(EDLSESSION_FLAG_CONTAINS_MARKERS);
+        assert_eq!(session, other_session);
+
+        other_session.tracks[0].events[0].is_muted = true;
+        assert_ne!(session, other_session);
+    }
+
+    #[test]
+    fn parse_extra_header_fields() {
+        let (session, warnings) = EDLParser::parse_with_warnings(FIXTURE_EXTRA_HEADER_FIELDS, encoding_rs::UTF_8).expect("unknown header fields should not fail the parse");
+
+        assert!(warnings.is_empty());
+        assert_eq!(session.extra_header, [("FOO BAR".to_string(), "Baz: Qux".to_string())]);
+        assert_eq!(session.bit_depth, BitDepth::Bit24);
+        assert_eq!(session.tracks.len(), 2);
+
+        let written = session.to_edl_string();
+        assert!(written.contains("# OF AUDIO FILES:\t2\nFOO BAR:\tBaz: Qux\n"));
+        assert_eq!(EDLParser::new().parse_str(&written).expect("written session should parse"), session);
+    }
+
+    #[test]
+    fn parse_unknown_sections() {
+        let (session, warnings) = EDLParser::parse_with_warnings(FIXTURE_UNKNOWN_SECTIONS, encoding_rs::UTF_8).expect("unknown sections fixture should parse");
+
+        assert_eq!(warnings, [
+            EDLWarning::UnknownSection { line: 34, value: "O F F L I N E  C L I P S  I N  S E S S I O N".to_string() },
+            EDLWarning::UnknownSection { line: 66, value: "S E S S I O N  N O T E S".to_string() },
+        ]);
+
+        assert_eq!(session.unknown_sections.len(), 2);
+        assert_eq!(session.unknown_sections[0].0, "O F F L I N E  C L I P S  I N  S E S S I O N");
+        assert_eq!(session.unknown_sections[0].1.len(), 2);
+        assert!(session.unknown_sections[0].1[1].starts_with("Missing_01-01"));
+        assert_eq!(session.unknown_sections[1].1, ["Reel:\t4", "Pickups due Friday"]);
+
+        assert_eq!(session.tracks.len(), 2);
+        assert_eq!(session.markers.len(), 2);
+
+        let written = session.to_edl_string();
+        assert!(written.ends_with("S E S S I O N  N O T E S\nReel:\t4\nPickups due Friday\n"));
+        assert_eq!(EDLParser::new().parse_str(&written).expect("written session should parse"), session);
+    }
+
+    #[test]
+    fn parse_duplicate_marker_ids() {
+        let (mut session, warnings) = EDLParser::parse_with_warnings(FIXTURE_DUPLICATE_MARKER_IDS, encoding_rs::UTF_8).expect("duplicate marker ids fixture should parse");
+
+        assert_eq!(warnings, [EDLWarning::DuplicateMarkerId { lines: vec![58, 60], id: 3 }]);
+        assert_eq!(session.markers.iter().map(|marker| marker.id).collect::<Vec<_>>(), [1, 3, 2, 3]);
+
+        session.sort_markers();
+        assert_eq!(session.markers.iter().map(|marker| marker.name.as_str()).collect::<Vec<_>>(), ["First Act", "Pickup", "Second Act", "Third Act"]);
+    }
+
+    #[test]
+    fn parse_marker_reference_consistent() {
+        let (session, warnings) = EDLParser::parse_with_warnings(FIXTURE_MARKER_REFERENCE_CONSISTENT, encoding_rs::UTF_8).expect("consistent marker reference fixture should parse");
+
+        assert!(warnings.is_empty(), "unexpected warnings: {warnings:?}");
+        assert_eq!(session.markers[1].time_reference, 288960);
+    }
+
+    #[test]
+    fn parse_marker_reference_mismatch() {
+        let (session, warnings) = EDLParser::parse_with_warnings(FIXTURE_MARKER_REFERENCE_MISMATCH, encoding_rs::UTF_8).expect("a mismatched marker reference should not fail the parse");
+
+        assert_eq!(warnings, [EDLWarning::MarkerReferenceMismatch {
+            line: 58,
+            location: Timecode::from_str("01:00:06:00", FrameRate::Fps25).unwrap(),
+            time_reference: 336000,
+        }]);
+        assert_eq!(session.markers.len(), 2);
+    }
+
+    #[test]
+    fn parse_header_field_order() {
+        let before_session = EDLParser::parse(FIXTURE_START_TIMECODE_BEFORE_FORMAT, encoding_rs::UTF_8).expect("start timecode before the format should parse");
+        let after_session = EDLParser::parse(FIXTURE_START_TIMECODE_AFTER_FORMAT, encoding_rs::UTF_8).expect("start timecode after the format should parse");
+
+        let start_timecode = Timecode::from_str("00:59:59:29", FrameRate::Fps30(false)).unwrap();
+        assert_eq!(before_session.fps, FrameRate::Fps30(false));
+        assert_eq!(before_session.start_timecode, start_timecode);
+        assert_eq!(after_session.fps, before_session.fps);
+        assert_eq!(after_session.start_timecode, before_session.start_timecode);
+        assert_eq!(after_session.content_hash(), before_session.content_hash());
+    }
+
+    #[test]
+    fn parse_missing_timecode_format() {
+        let (session, warnings) = EDLParser::parse_with_warnings(FIXTURE_MISSING_TIMECODE_FORMAT, encoding_rs::UTF_8).expect("missing timecode format fixture should parse");
+
+        assert_eq!(warnings, [EDLWarning::MissingFrameRate { fallback: FrameRate::Fps30(true) }]);
+        assert_eq!(session.fps, FrameRate::Fps30(true));
+        assert!(session.start_timecode.is_drop_frame());
+        assert_eq!(session.tracks[0].events[1].time_out, Timecode::from_str("01:00:10;12", FrameRate::Fps30(true)).unwrap());
+    }
+
+    #[test]
+    fn parse_unknown_timecode_format() {
+        let edl_text = std::fs::read_to_string(FIXTURE_BASIC_SESSION).unwrap().replacen("25 Frame", "25 fps", 1);
+
+        let mut parser = EDLParser::new();
+        let session = parser.parse_str(&edl_text).expect("an unknown timecode format should fall back");
+        assert_eq!(session.fps, FrameRate::Fps25);
+        assert_eq!(parser.warnings(), [EDLWarning::UnknownFrameRate { line: 5, value: "25 fps".to_string(), fallback: FrameRate::Fps25 }]);
+
+        assert_eq!(
+            EDLParser::new().strict(true).parse_str(&edl_text).map(|_| ()),
+            Err(EDLError::UnknownFrameRate { line: 5, value: "25 fps".to_string() })
+        );
+    }
+
+    #[test]
+    fn parse_table_invalid_timecode() {
+        let table = [
+            "CHANNEL\tEVENT\tCLIP NAME\tSTART TIME\tEND TIME\tDURATION\tSTATE".to_string(),
+            "1\t1\tClip\t01:00:00:00\t01:xx:00:00\t00:00:00:00\tUnmuted".to_string(),
+        ];
+
+        let result = EDLTrackEvent::parse_table(&table, FrameRate::Fps25);
+        assert!(matches!(result, Err(EDLError::InvalidTimecode { line: 2, column: 5, .. })));
+    }
+
+    #[test]
+    fn parse_track_without_comment() {
+        let session = EDLParser::parse(FIXTURE_TRACK_WITHOUT_COMMENT, encoding_rs::UTF_8).expect("track without comment fixture should parse");
+
+        assert_eq!(session.tracks.len(), 2);
+        assert_eq!(session.tracks[0].name, "DIA");
+        assert_eq!(session.tracks[0].comment, "");
+        assert_eq!(session.tracks[0].plugins, ["EQ3 7-Band (mono)"]);
+        assert_eq!(session.tracks[0].events.len(), 2);
+        assert_eq!(session.tracks[0].events[0].name, "Dialog_01-01");
+        assert_eq!(session.tracks[1].events.len(), 2);
+    }
+
+    #[test]
+    fn parse_tracks_with_and_without_plugins() {
+        let session = EDLParser::parse(FIXTURE_TRACK_PLUGINS_MIXED, encoding_rs::UTF_8).expect("mixed plugins fixture should parse");
+
+        assert!(session.check_flag(EDLSESSION_FLAG_CONTAINS_PLUGIN));
+        assert_eq!(session.tracks.len(), 3);
+
+        assert_eq!(session.tracks[0].comment, "Boom and lav");
+        assert_eq!(session.tracks[0].plugins, ["EQ3 7-Band (mono)"]);
+        assert_eq!(session.tracks[0].events.len(), 2);
+
+        assert!(session.tracks[1].plugins.is_empty());
+        assert_eq!(session.tracks[1].state, EDLTrackState { muted: true, solo: true, ..EDLTrackState::default() });
+        assert_eq!(session.tracks[1].events.len(), 1);
+        assert_eq!(session.tracks[1].events[0].name, "Music_01");
+
+        assert!(session.tracks[2].plugins.is_empty());
+        assert_eq!(session.tracks[2].events.len(), 2);
+        assert_eq!(session.tracks[2].events[0].name, "Dialog_01-02");
+    }
+
+    #[test]
+    fn parse_empty_plugins_listing() {
+        let (session, warnings) = EDLParser::parse_with_warnings(FIXTURE_EMPTY_PLUGINS_LISTING, encoding_rs::UTF_8).expect("empty plugins listing fixture should parse");
+
+        assert!(warnings.is_empty());
+        assert!(session.plugins.is_empty());
+        assert!(!session.check_flag(EDLSESSION_FLAG_CONTAINS_PLUGIN));
+        assert!(session.check_flag(EDLSESSION_FLAG_CONTAINS_ONLINE_FILES | EDLSESSION_FLAG_CONTAINS_TRACKS));
+
+        assert_eq!(session.tracks.len(), 2);
+        assert_eq!(session.tracks[0].comment, "Main dialog");
+        assert_eq!(session.tracks[0].events.len(), 2);
+        assert_eq!(session.tracks[1].state, EDLTrackState { muted: true, solo: true, ..EDLTrackState::default() });
+        assert_eq!(session.tracks[1].events.len(), 2);
+        assert!(!session.to_edl_string().contains("PLUG-INS"));
+    }
+
+    #[test]
+    fn parse_clip_name_with_tabs() {
+        let (session, warnings) = EDLParser::parse_with_warnings(FIXTURE_CLIP_NAME_WITH_TABS, encoding_rs::UTF_8).expect("clip name with tabs fixture should parse");
+
+        assert!(warnings.is_empty());
+        assert_eq!(session.tracks[0].events.len(), 2);
+        assert_eq!(session.tracks[0].events[1].name, "Dialog_01\tAlt-02");
+        assert_eq!(session.tracks[0].events[1].time_in, Timecode::from_str("01:00:06:00", FrameRate::Fps25).unwrap());
+        assert!(session.tracks[0].events[1].is_muted);
+
+        assert_eq!(session.tracks[1].events.len(), 2);
+        assert_eq!(session.tracks[1].events[1].name, "Music_01\tStem\tR");
+        assert_eq!(session.tracks[1].events[1].channel, 2);
+        assert_eq!(session.tracks[1].events[1].timestamp, Timecode::from_str("01:00:02:00", FrameRate::Fps25).unwrap());
+    }
+
+    #[test]
+    fn parse_mixed_drop_frame_rates_seen() {
+        let session = EDLParser::parse(FIXTURE_MIXED_DROP_FRAME, encoding_rs::UTF_8).expect("mixed drop-frame fixture should parse");
+
+        assert_eq!(session.fps, FrameRate::Fps30(true));
+        assert_eq!(session.distinct_frame_rates_seen(), [FrameRate::Fps30(true), FrameRate::Fps30(false)]);
+    }
+
+    #[test]
+    fn parse_bytes_valid_and_bom() {
+        let bytes = std::fs::read(FIXTURE_BASIC_SESSION).expect("basic session fixture should be readable");
+        let (session, warnings) = EDLParser::parse_bytes(&bytes, encoding_rs::UTF_8).expect("basic session bytes should parse");
+
+        assert!(warnings.is_empty());
+        assert_eq!(session.name, "Timeline Kit Demo");
+        assert_eq!(session.tracks.len(), 2);
+
+        let bom_bytes = [b"\xEF\xBB\xBF".as_slice(), bytes.as_slice()].concat();
+        let (bom_session, bom_warnings) = EDLParser::parse_bytes(&bom_bytes, encoding_rs::UTF_8).expect("basic session bytes with a BOM should parse");
+
+        assert!(bom_warnings.is_empty());
+        assert_eq!(bom_session.content_hash(), session.content_hash());
+    }
+
+    #[test]
+    fn parse_bytes_corrupt() {
+        let text = std::fs::read_to_string(FIXTURE_BASIC_SESSION).expect("basic session fixture should be readable");
+        let corrupt_text = text.replacen("Music_01.wav                            \tMacintosh HD", "Music_01.wav\tExtra Column\tMacintosh HD", 1);
+        let corrupt_bytes = [corrupt_text.as_bytes(), b"\xFF\xFE".as_slice()].concat();
+
+        let (session, warnings) = EDLParser::parse_bytes(&corrupt_bytes, encoding_rs::UTF_8).expect("recoverable corruption should not fail the parse");
+        assert_eq!(session.files.online_files.len(), 1);
+        assert!(warnings.contains(&EDLWarning::MalformedEncoding));
+        assert!(warnings.contains(&EDLWarning::SkippedRow { line: 19 }));
+
+        let garbage = EDLParser::parse_bytes(b"\x00\x01SESSION\tNAME\n\xC3", encoding_rs::UTF_8);
+        assert!(matches!(garbage, Err(EDLError::UnexpectedField { line: 1, .. })));
+
+        let original_bytes = text.as_bytes();
+        for end in 0..original_bytes.len() {
+            let _ = EDLParser::parse_bytes(&original_bytes[..end], encoding_rs::UTF_8);
         }
-        Err("".to_string())
+    }
+
+    #[test]
+    fn parse_with_warnings_short_row() {
+        let (session, warnings) = EDLParser::parse_with_warnings(FIXTURE_SHORT_MARKER_ROW, encoding_rs::UTF_8).expect("a short row should not fail the parse");
+        assert_eq!(session.markers.len(), 1);
+        assert_eq!(warnings, vec![EDLWarning::SkippedRow { line: 58 }]);
+
+        let (_, basic_warnings) = EDLParser::parse_with_warnings(FIXTURE_BASIC_SESSION, encoding_rs::UTF_8).expect("basic session fixture should parse");
+        assert!(basic_warnings.is_empty());
+    }
+
+    #[test]
+    fn configured_parser_reused_across_files() {
+        let mut edl_parser = EDLParser::new().encoding(encoding_rs::UTF_8);
+
+        let basic_session = edl_parser.parse_path(FIXTURE_BASIC_SESSION).expect("basic session fixture should parse");
+        assert_eq!(basic_session.tracks.len(), 2);
+        assert!(edl_parser.warnings().is_empty());
+
+        let short_row_session = edl_parser.parse_path(FIXTURE_SHORT_MARKER_ROW).expect("a short row should not fail the parse");
+        assert_eq!(short_row_session.markers.len(), 1);
+        assert_eq!(edl_parser.warnings(), [EDLWarning::SkippedRow { line: 58 }]);
+
+        let reparsed = edl_parser.parse_str(&std::fs::read_to_string(FIXTURE_BASIC_SESSION).unwrap()).expect("basic session text should parse");
+        assert_eq!(reparsed.content_hash(), basic_session.content_hash());
+        assert!(edl_parser.warnings().is_empty());
+
+        let mut strict_parser = EDLParser::new().strict(true);
+        assert!(strict_parser.parse_path(FIXTURE_BASIC_SESSION).is_ok());
+        assert_eq!(
+            strict_parser.parse_path(FIXTURE_SHORT_MARKER_ROW).map(|_| ()),
+            Err(EDLError::ColumnCountMismatch { line: 58, expected: 6, found: 5 }),
+        );
+    }
+
+    #[test]
+    fn parse_duration_mismatch_warning() {
+        let text = std::fs::read_to_string(FIXTURE_BASIC_SESSION).expect("basic session fixture should be readable");
+        let mismatched_text = text.replacen("00:00:18:00", "00:00:18:01", 1);
+
+        let (session, warnings) = EDLParser::parse_bytes(mismatched_text.as_bytes(), encoding_rs::UTF_8).expect("a duration mismatch should not fail the parse");
+        assert_eq!(session.tracks[1].events[0].duration, Timecode::from_str("00:00:18:01", FrameRate::Fps25).unwrap());
+        assert_eq!(
+            warnings,
+            vec![EDLWarning::DurationMismatch {
+                line: 51,
+                duration: Timecode::from_str("00:00:18:01", FrameRate::Fps25).unwrap(),
+                span: Timecode::from_str("00:00:18:00", FrameRate::Fps25).unwrap(),
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_track_event_column_widths() {
+        let timecode = |tc_string: &str| Timecode::from_str(tc_string, FrameRate::Fps25).expect("test timecode should be valid");
+
+        for fixture in [FIXTURE_TRACK_EVENTS_6_COLUMNS, FIXTURE_TRACK_EVENTS_7_COLUMNS, FIXTURE_TRACK_EVENTS_7_COLUMNS_TIMESTAMP, FIXTURE_TRACK_EVENTS_8_COLUMNS] {
+            let (session, warnings) = EDLParser::parse_with_warnings(fixture, encoding_rs::UTF_8).expect("event table fixture should parse");
+            assert!(warnings.is_empty(), "{fixture}: {warnings:?}");
+
+            let dialog_event = &session.tracks[0].events[1];
+            assert_eq!(dialog_event.channel, 1);
+            assert_eq!(dialog_event.event, 2);
+            assert_eq!(dialog_event.name, "Dialog_01-02");
+            assert_eq!(dialog_event.time_in, timecode("01:00:06:00"));
+            assert_eq!(dialog_event.time_out, timecode("01:00:10:12"));
+            assert_eq!(dialog_event.duration, timecode("00:00:04:12"));
+            assert!(dialog_event.is_muted);
+        }
+
+        let mono_session = EDLParser::parse(FIXTURE_TRACK_EVENTS_6_COLUMNS, encoding_rs::UTF_8).expect("6 column fixture should parse");
+        assert_eq!(mono_session.tracks[1].events.len(), 1);
+        assert_eq!(mono_session.tracks[0].events[0].timestamp, Timecode::with_fps(FrameRate::Fps25));
+
+        let timestamp_session = EDLParser::parse(FIXTURE_TRACK_EVENTS_8_COLUMNS, encoding_rs::UTF_8).expect("8 column fixture should parse");
+        assert_eq!(timestamp_session.tracks[0].events[1].timestamp, timecode("01:00:06:00"));
+
+        // Without a CHANNEL column the TIMESTAMP column is second to last,
+        // and STATE is still read from the column named in the header
+        let mono_timestamp_session = EDLParser::parse(FIXTURE_TRACK_EVENTS_7_COLUMNS_TIMESTAMP, encoding_rs::UTF_8).expect("7 column fixture with timestamps should parse");
+        assert_eq!(mono_timestamp_session.tracks[0].events[1].timestamp, timecode("00:59:36:00"));
+        assert!(mono_timestamp_session.tracks[0].events[1].check_flag(EDLEVENT_FLAG_HAS_TIMESTAMP));
+        assert!(!mono_timestamp_session.tracks[0].events[0].is_muted);
+        assert_eq!(mono_timestamp_session.tracks[1].events[0].timestamp, timecode("00:59:32:00"));
+
+        let table = ["EVENT\tCLIP NAME\tSTART TIME\tEND TIME\tSTATE", "1\tClip\t01:00:00:00\t01:00:01:00\tUnmuted"];
+        assert_eq!(EDLTrackEvent::parse_table(&table, FrameRate::Fps25), Err(EDLError::MissingColumn { line: 1, column: "DURATION".to_string() }));
+    }
+
+    #[test]
+    fn parse_files_without_location() {
+        let (offline_session, offline_warnings) = EDLParser::parse_with_warnings(FIXTURE_OFFLINE_FILE_WITHOUT_LOCATION, encoding_rs::UTF_8).expect("an offline file without a location should parse");
+        assert!(offline_warnings.is_empty());
+        assert_eq!(offline_session.files.offline_files, vec![EDLMediaFile { file_name: "Missing_01.wav".to_string(), location: None, ..EDLMediaFile::default() }]);
+
+        let (online_session, online_warnings) = EDLParser::parse_with_warnings(FIXTURE_ONLINE_FILE_WITHOUT_LOCATION, encoding_rs::UTF_8).expect("an online file without a location should parse");
+        assert_eq!(online_session.files.online_files[1].location, None);
+        assert_eq!(online_session.files.online_files[0].location.as_deref(), Some("Macintosh HD:Sessions:Demo:Audio Files:"));
+        assert_eq!(online_warnings, vec![EDLWarning::MissingFileLocation { line: 19, file_name: "Music_01.wav".to_string() }]);
+    }
+
+    #[test]
+    fn parse_records_source_lines() {
+        let session = EDLParser::parse(FIXTURE_BASIC_SESSION, encoding_rs::UTF_8).expect("basic session fixture should parse");
+
+        let online_file_lines = session.files.online_files.iter().map(|file| file.source_line.0).collect::<Vec<_>>();
+        let offline_file_lines = session.files.offline_files.iter().map(|file| file.source_line.0).collect::<Vec<_>>();
+        let clip_lines = session.files.online_clips.iter().map(|clip| clip.source_line.0).collect::<Vec<_>>();
+        let marker_lines = session.markers.iter().map(|marker| marker.source_line.0).collect::<Vec<_>>();
+
+        assert_eq!(online_file_lines, [Some(18), Some(19)]);
+        assert_eq!(offline_file_lines, [Some(24)]);
+        assert_eq!(clip_lines, [Some(29), Some(30), Some(31)]);
+        assert_eq!(marker_lines, [Some(57), Some(58)]);
+
+        let mut relocated_clip = session.files.online_clips[0].clone();
+        relocated_clip.source_line = EDLSourceLine(None);
+        assert_eq!(relocated_clip, session.files.online_clips[0]);
+    }
+
+    #[test]
+    fn parse_auto_detects_encoding() {
+        let utf8_session = EDLParser::parse_auto(FIXTURE_BASIC_SESSION).expect("UTF-8 fixture should parse");
+        let utf16le_session = EDLParser::parse_auto(FIXTURE_BASIC_SESSION_UTF16LE_BOM).expect("UTF-16LE fixture should parse");
+        let utf16be_session = EDLParser::parse_auto(FIXTURE_BASIC_SESSION_UTF16BE_BOM).expect("UTF-16BE fixture should parse");
+
+        assert_eq!(utf8_session.name, "Timeline Kit Demo");
+        assert_eq!(utf8_session.tracks.len(), 2);
+        assert_eq!(utf16le_session.content_hash(), utf8_session.content_hash());
+        assert_eq!(utf16be_session.content_hash(), utf8_session.content_hash());
+    }
+
+    #[test]
+    fn parse_mixed_line_endings() {
+        let session = EDLParser::parse(FIXTURE_BASIC_SESSION, encoding_rs::UTF_8).expect("basic session fixture should parse");
+        let (crlf_session, warnings) = EDLParser::parse_bytes(
+            &std::fs::read(FIXTURE_BASIC_SESSION_CRLF).expect("CRLF fixture should be readable"),
+            encoding_rs::UTF_8,
+        ).expect("CRLF fixture should parse");
+
+        assert!(warnings.is_empty());
+        assert_eq!(crlf_session.files.online_files.len(), 2);
+        assert_eq!(crlf_session.tracks.len(), 2);
+        assert_eq!(crlf_session.markers.len(), 2);
+        assert_eq!(crlf_session.content_hash(), session.content_hash());
+    }
+
+    #[test]
+    fn parse_corrupt_timecode_reports_file_line() {
+        let error = EDLParser::parse(FIXTURE_CORRUPT_TIMECODE, encoding_rs::UTF_8).expect_err("corrupt timecode fixture should not parse");
+
+        assert!(matches!(error, EDLError::InvalidTimecode { line: 42, column: 5, .. }));
+        assert_eq!(error.line(), Some(42));
+        assert!(error.to_string().starts_with("invalid timecode '01:00:1O:12' at line 42"));
+    }
+
+    #[test]
+    fn parse_markers_section_presence() {
+        let edl_text = std::fs::read_to_string(FIXTURE_BASIC_SESSION).expect("basic session fixture should be readable");
+        let markers_start = edl_text.find(EDLSection::MarkersListing.section_name()).expect("basic session fixture should have a markers section");
+        let markers_header_end = markers_start + edl_text[markers_start..].match_indices('\n').nth(1).map(|(i, _)| i + 1).unwrap();
+
+        let (with_markers, _) = EDLParser::parse_bytes(edl_text.as_bytes(), encoding_rs::UTF_8).expect("basic session should parse");
+        let (without_markers, _) = EDLParser::parse_bytes(&edl_text.as_bytes()[..markers_start], encoding_rs::UTF_8).expect("session without markers should parse");
+        let (empty_markers, _) = EDLParser::parse_bytes(&edl_text.as_bytes()[..markers_header_end], encoding_rs::UTF_8).expect("session with an empty markers section should parse");
+
+        assert_eq!(with_markers.markers.len(), 2);
+        assert!(with_markers.check_flag(EDLSESSION_FLAG_CONTAINS_MARKERS));
+
+        assert!(without_markers.markers.is_empty());
+        assert!(!without_markers.check_flag(EDLSESSION_FLAG_CONTAINS_MARKERS));
+
+        assert!(empty_markers.markers.is_empty());
+        assert!(empty_markers.check_flag(EDLSESSION_FLAG_CONTAINS_MARKERS));
+
+        for session in [&with_markers, &without_markers, &empty_markers] {
+            assert!(session.check_flag(EDLSESSION_FLAG_CONTAINS_PLUGIN | EDLSESSION_FLAG_CONTAINS_ONLINE_FILES | EDLSESSION_FLAG_CONTAINS_OFFLINE_FILES));
+            assert!(session.check_flag(EDLSESSION_FLAG_CONTAINS_ONLINE_CLIPS | EDLSESSION_FLAG_CONTAINS_TRACKS));
+        }
+    }
+
+    #[test]
+    fn parse_many_keeps_going_after_failures() {
+        let missing_path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/does_not_exist.txt");
+        let input_paths = [FIXTURE_BASIC_SESSION, FIXTURE_CORRUPT_TIMECODE, missing_path, FIXTURE_TRACK_WITHOUT_COMMENT];
+        let results = EDLParser::parse_many(&input_paths, encoding_rs::UTF_8);
+
+        assert_eq!(results.iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>(), input_paths);
+        assert_eq!(results[0].1.as_ref().map(|session| session.name.as_str()), Ok("Timeline Kit Demo"));
+        assert!(matches!(results[1].1, Err(EDLError::InvalidTimecode { line: 42, .. })));
+        assert!(matches!(results[2].1, Err(EDLError::Io(_))));
+        assert!(results[3].1.is_ok());
+    }
+
+    #[test]
+    fn parse_duplicate_track_names_warning() {
+        let (session, warnings) = EDLParser::parse_with_warnings(FIXTURE_DUPLICATE_TRACK_NAMES, encoding_rs::UTF_8).expect("duplicate track names fixture should parse");
+
+        assert_eq!(session.tracks.len(), 2);
+        assert_eq!(warnings, [EDLWarning::DuplicateTrackName { lines: vec![35, 45], name: "DIA".to_string() }]);
+
+        let (_, warnings) = EDLParser::parse_with_warnings(FIXTURE_BASIC_SESSION, encoding_rs::UTF_8).expect("basic session fixture should parse");
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn parse_track_delays() {
+        let session = EDLParser::parse(FIXTURE_TRACK_DELAYS, encoding_rs::UTF_8).expect("track delays fixture should parse");
+        let delays = session.tracks.iter().map(|track| track.delay.clone()).collect::<Vec<_>>();
+
+        assert_eq!(delays, [
+            EDLTrackDelay { amount: 512, unit: EDLUnit::Samples },
+            EDLTrackDelay { amount: 0, unit: EDLUnit::Samples },
+            EDLTrackDelay { amount: -64, unit: EDLUnit::Samples },
+        ]);
+
+        let bad_unit = std::fs::read_to_string(FIXTURE_TRACK_DELAYS).unwrap().replacen("512 Samples", "512 Parsecs", 1);
+        assert_eq!(
+            EDLParser::parse_bytes(bad_unit.as_bytes(), encoding_rs::UTF_8).map(|_| ()),
+            Err(EDLError::UnknownUnit { line: 37, column: 2, value: "Parsecs".to_string() }),
+        );
+    }
+
+    #[test]
+    fn parse_missing_file() {
+        let missing_path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/does_not_exist.txt");
+        let error = EDLParser::parse(missing_path, encoding_rs::UTF_8).expect_err("missing file should not parse");
+
+        assert!(matches!(error, EDLError::Io(_)));
+        assert_eq!(error.line(), None);
+        assert!(error.to_string().contains(missing_path));
+        assert!(matches!(EDLParser::parse_auto(missing_path), Err(EDLError::Io(_))));
     }
 }
@@ -0,0 +1,165 @@
+// Copyright (C) Stefan Olivier
+// <https://stefanolivier.com>
+
+use thiserror::Error;
+
+use crate::chrono::{Timecode, TimecodeParseError};
+use crate::format::FrameRate;
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLError` Declaration --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// Errors that can be produced while parsing a Protools EDL
+///
+/// Line numbers are 1-based. Errors returned by `EDLParser` refer to lines
+/// of the input file. Errors returned by `ParseTable` implementations
+/// called directly refer to the line within the table data that was passed
+/// in, where the table's header row is line 1.
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum EDLError {
+    #[error("could not read EDL file: {0}")]
+    Io(String),
+
+    #[error("unexpected field '{field}' at line {line}")]
+    UnexpectedField { line: usize, field: String },
+
+    #[error("invalid number '{value}' at line {line}, column {column}")]
+    InvalidNumber { line: usize, column: usize, value: String },
+
+    #[error("invalid timecode '{value}' at line {line}, column {column}: {source}")]
+    InvalidTimecode { line: usize, column: usize, value: String, source: TimecodeParseError },
+
+    #[error("unknown sample rate '{value}' at line {line}")]
+    UnknownSampleRate { line: usize, value: String },
+
+    #[error("unknown bit depth '{value}' at line {line}")]
+    UnknownBitDepth { line: usize, value: String },
+
+    #[error("unknown timecode format '{value}' at line {line}")]
+    UnknownFrameRate { line: usize, value: String },
+
+    #[error("invalid marker location '{value}' at line {line}, column {column}")]
+    InvalidMarkerLocation { line: usize, column: usize, value: String },
+
+    #[error("unknown unit '{value}' at line {line}, column {column}")]
+    UnknownUnit { line: usize, column: usize, value: String },
+
+    #[error("unknown plug-in format '{value}' at line {line}, column {column}")]
+    UnknownPluginFormat { line: usize, column: usize, value: String },
+
+    #[error("table header at line {line} is missing the '{column}' column")]
+    MissingColumn { line: usize, column: String },
+
+    #[error("row at line {line} has {found} columns, expected {expected}")]
+    ColumnCountMismatch { line: usize, expected: usize, found: usize },
+}
+
+impl EDLError {
+    /// The 1-based line the error refers to, if it refers to one
+    pub fn line(&self) -> Option<usize> {
+        match *self {
+            EDLError::Io(_) => None,
+            EDLError::UnexpectedField { line, .. }
+            | EDLError::InvalidNumber { line, .. }
+            | EDLError::InvalidTimecode { line, .. }
+            | EDLError::UnknownSampleRate { line, .. }
+            | EDLError::UnknownBitDepth { line, .. }
+            | EDLError::UnknownFrameRate { line, .. }
+            | EDLError::InvalidMarkerLocation { line, .. }
+            | EDLError::UnknownUnit { line, .. }
+            | EDLError::UnknownPluginFormat { line, .. }
+            | EDLError::MissingColumn { line, .. }
+            | EDLError::ColumnCountMismatch { line, .. } => Some(line),
+        }
+    }
+
+    /// Replaces the line the error refers to with `f(line)`
+    pub(super) fn map_line(mut self, f: impl FnOnce(usize) -> usize) -> Self {
+        match &mut self {
+            EDLError::Io(_) => {},
+            EDLError::UnexpectedField { line, .. }
+            | EDLError::InvalidNumber { line, .. }
+            | EDLError::InvalidTimecode { line, .. }
+            | EDLError::UnknownSampleRate { line, .. }
+            | EDLError::UnknownBitDepth { line, .. }
+            | EDLError::UnknownFrameRate { line, .. }
+            | EDLError::InvalidMarkerLocation { line, .. }
+            | EDLError::UnknownUnit { line, .. }
+            | EDLError::UnknownPluginFormat { line, .. }
+            | EDLError::MissingColumn { line, .. }
+            | EDLError::ColumnCountMismatch { line, .. } => *line = f(*line),
+        }
+
+        self
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLWarning` Declaration --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// Non-fatal issues found while parsing a Protools EDL, reported alongside
+/// the parsed session by `EDLParser::parse_with_warnings` and
+/// `EDLParser::parse_bytes`
+///
+/// Line numbers are 1-based and refer to lines of the input.
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum EDLWarning {
+    #[error("input contained byte sequences that are invalid for the encoding and were replaced")]
+    MalformedEncoding,
+
+    #[error("unknown section '{value}' at line {line} was kept unparsed")]
+    UnknownSection { line: usize, value: String },
+
+    #[error("row at line {line} does not match the table's columns and was skipped")]
+    SkippedRow { line: usize },
+
+    #[error("event duration {duration} at line {line} does not match its start and end times, which span {span}")]
+    DurationMismatch { line: usize, duration: Timecode, span: Timecode },
+
+    #[error("online file '{file_name}' at line {line} has no location, and may need relinking")]
+    MissingFileLocation { line: usize, file_name: String },
+
+    #[error("track name '{name}' is used by more than one track, at lines {lines:?}")]
+    DuplicateTrackName { lines: Vec<usize>, name: String },
+
+    #[error("marker id {id} is used by more than one marker, at lines {lines:?}")]
+    DuplicateMarkerId { lines: Vec<usize>, id: u32 },
+
+    #[error("marker at line {line} is located at {location} but its time reference of {time_reference} samples is more than a frame away")]
+    MarkerReferenceMismatch { line: usize, location: Timecode, time_reference: u32 },
+
+    #[error("header has no timecode format, so timecodes were read at {fallback} fps")]
+    MissingFrameRate { fallback: FrameRate },
+
+    #[error("unknown timecode format '{value}' at line {line}, so timecodes were read at {fallback} fps")]
+    UnknownFrameRate { line: usize, value: String, fallback: FrameRate },
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLValidationWarning` Declaration --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// Inconsistencies between an `EDLSession`'s header totals and its parsed
+/// contents, reported by `EDLSession::validate`
+///
+/// These usually point at a truncated file or a parsing bug rather than a
+/// malformed EDL, so they are diagnostics rather than errors.
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum EDLValidationWarning {
+    #[error("header lists {expected} audio tracks but {found} were parsed")]
+    TrackCountMismatch { expected: u32, found: usize },
+
+    #[error("header lists {expected} audio clips but {found} were parsed")]
+    ClipCountMismatch { expected: u32, found: usize },
+
+    #[error("header lists {expected} audio files but {found} were parsed")]
+    FileCountMismatch { expected: u32, found: usize },
+}
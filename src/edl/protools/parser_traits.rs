@@ -2,6 +2,8 @@
 // Copyright (C) Stefan Olivier
 // <https://stefanolivier.com>
 
+use crate::edl::protools::EDLError;
+
 ///////////////////////////////////////////////////////////////////////////
 //
 //  -- @SECTION `EDLParser` Traits --
@@ -12,7 +14,49 @@ pub trait ParseField<T> {
     fn parse_field(field_string: &str) -> Option<T>;
 }
 
+/// Parses the rows of one tab separated table of a Protools EDL into `T`
+///
+/// `table_data` holds the table's lines, starting with its header row, with
+/// any section heading and trailing blank lines already removed. Rows whose
+/// column count does not match the table are skipped. `defaults` carries
+/// context the table does not spell out, such as the session frame rate for
+/// timecode columns. Errors report lines within `table_data`, where the
+/// header row is line 1.
+///
+/// This is what `EDLParser` uses for each table, and it can also be called
+/// directly on lines taken from other tools' output.
 pub trait ParseTable<T, D> {
+    /// The number of columns in a full row of the table
     const TABLE_TOTAL_COLUMNS: usize;
-    fn parse_table(table_data: &[String], defaults: D) -> Option<Vec<T>>;
+
+    /// Parses every row below the header row of `table_data`
+    fn parse_table<S: AsRef<str>>(table_data: &[S], defaults: D) -> Result<Vec<T>, EDLError>;
+
+    /// The number of columns the rows below `header_row` are expected to have
+    fn expected_columns(_header_row: &str) -> usize {
+        Self::TABLE_TOTAL_COLUMNS
+    }
+
+    /// Returns `true` if `line` has a column layout that `parse_table`
+    /// reads as a row below `header_row`, rather than skipping it
+    fn is_table_row(header_row: &str, line: &str) -> bool {
+        line.split('\t').count() == Self::expected_columns(header_row)
+    }
+
+    /// Like `parse_table`, but fails on the first row below the header row
+    /// that `parse_table` would skip, rather than skipping it
+    fn parse_table_strict<S: AsRef<str>>(table_data: &[S], defaults: D) -> Result<Vec<T>, EDLError> {
+        let header_row = table_data.first().map_or("", |header_row| header_row.as_ref());
+        for (i, line) in table_data.iter().enumerate().skip(1) {
+            if !Self::is_table_row(header_row, line.as_ref()) {
+                return Err(EDLError::ColumnCountMismatch {
+                    line: i + 1,
+                    expected: Self::expected_columns(header_row),
+                    found: line.as_ref().split('\t').count(),
+                });
+            }
+        }
+
+        Self::parse_table(table_data, defaults)
+    }
 }
@@ -15,6 +15,11 @@ pub(super) const EDL_FIELD_NAME_INDEX: usize = 0;
 pub(super) const EDL_FIELD_VALUE_INDEX: usize = 1;
 pub(super) const EDL_TRACK_EVENT_VALID_COLUMN_WIDTHS: [usize; 4] = [2, 6, 7, 8];
 pub(super) const EDLPARSER_MASK_SECTION_PLUGINSLISTING: u8 = 0b00000001;
+pub(super) const EDLPARSER_MASK_SECTION_ONLINEFILES: u8 = 0b00000010;
+pub(super) const EDLPARSER_MASK_SECTION_OFFLINEFILES: u8 = 0b00000100;
+pub(super) const EDLPARSER_MASK_SECTION_ONLINECLIPS: u8 = 0b00001000;
+pub(super) const EDLPARSER_MASK_SECTION_TRACKLISTING: u8 = 0b00010000;
+pub(super) const EDLPARSER_MASK_SECTION_MARKERSLISTING: u8 = 0b00100000;
 
 ///////////////////////////////////////////////////////////////////////////
 //
@@ -67,6 +72,37 @@ impl EDLSection {
     pub(super) const fn as_usize(self) -> usize {
         self as usize
     }
+
+    /// The `EDLParser` flag that records the section's banner was seen, or
+    /// `0` for sections without a banner
+    pub(super) const fn parser_mask(&self) -> u8 {
+        match self {
+            EDLSection::PluginsListing => EDLPARSER_MASK_SECTION_PLUGINSLISTING,
+            EDLSection::OnlineFiles => EDLPARSER_MASK_SECTION_ONLINEFILES,
+            EDLSection::OfflineFiles => EDLPARSER_MASK_SECTION_OFFLINEFILES,
+            EDLSection::OnlineClips => EDLPARSER_MASK_SECTION_ONLINECLIPS,
+            EDLSection::TrackListing => EDLPARSER_MASK_SECTION_TRACKLISTING,
+            EDLSection::MarkersListing => EDLPARSER_MASK_SECTION_MARKERSLISTING,
+            EDLSection::Header | EDLSection::Unknown => 0,
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLSectionOutcome` Implementation --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// What parsing one listing section of an EDL found
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(super) enum EDLSectionOutcome {
+    /// The section's banner did not appear in the input
+    Absent,
+    /// The section's banner appeared, but the section had no rows
+    Empty,
+    /// The section had this many rows, or tracks for the track listing
+    Parsed(usize),
 }
 
 ///////////////////////////////////////////////////////////////////////////
@@ -89,7 +125,39 @@ pub(super) enum EDLTrackEventColumn {
 }
 
 impl EDLTrackEventColumn {
-    fn column_name(&self) -> &'static str {
+    pub(super) const fn all_variants() -> &'static [EDLTrackEventColumn; EDLTRACKEVENTCOLUMN_SIZE] {
+        use EDLTrackEventColumn::*;
+        &[
+            Channel,
+            Event,
+            ClipName,
+            StartTime,
+            EndTime,
+            Duration,
+            Timestamp,
+            State,
+        ]
+    }
+
+    /// Maps a header row column name, e.g. `"CLIP NAME"`, to its column
+    pub(super) fn from_column_name(column_name: &str) -> Option<Self> {
+        EDLTrackEventColumn::all_variants()
+            .iter()
+            .copied()
+            .find(|column| column.column_name() == column_name.trim())
+    }
+
+    /// Returns `true` for the columns that some exports leave out, which
+    /// are CHANNEL for mono tracks and TIMESTAMP
+    pub(super) const fn is_optional(&self) -> bool {
+        matches!(self, EDLTrackEventColumn::Channel | EDLTrackEventColumn::Timestamp)
+    }
+
+    pub(super) const fn as_usize(self) -> usize {
+        self as usize
+    }
+
+    pub(super) const fn column_name(&self) -> &'static str {
         match self {
             EDLTrackEventColumn::Channel => "CHANNEL",
             EDLTrackEventColumn::Event => "EVENT",
@@ -170,12 +238,7 @@ impl EDLField {
 
     pub(super) const fn is_voidable(&self) -> bool {
         use EDLField::*;
-        match self {
-            TrackComment => true,
-            TrackState => true,
-            Unknown => true,
-            _ => false,
-        }
+        matches!(self, TrackComment | TrackState | Unknown)
     }
 }
 
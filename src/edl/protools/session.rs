@@ -1,6 +1,8 @@
 // Copyright (C) Stefan Olivier
 // <https://stefanolivier.com>
 
+use std::hash::{Hash, Hasher};
+
 use crate::edl::protools::*;
 use crate::chrono::{
     Timecode,
@@ -18,6 +20,7 @@ use crate::format::{
 ///////////////////////////////////////////////////////////////////////////
 
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EDLSession {
     pub name: String,
     pub sample_rate: SampleRate,
@@ -31,6 +34,14 @@ pub struct EDLSession {
     pub markers: Vec<EDLMarker>,
     pub plugins: Vec<EDLPlugin>,
     pub tracks: Vec<EDLTrack>,
+    /// Header fields this crate does not recognize, as `(name, value)`
+    /// pairs in the order they appeared, which are written back out after
+    /// the known header fields
+    pub extra_header: Vec<(String, String)>,
+    /// Sections this crate does not recognize, as their banner and their
+    /// non-empty lines, in the order they appeared, which are written back
+    /// out after the known sections
+    pub unknown_sections: Vec<(String, Vec<String>)>,
     flags: u64,
 }
 
@@ -49,10 +60,259 @@ impl EDLSession {
             markers: Vec::<EDLMarker>::default(),
             plugins: Vec::<EDLPlugin>::default(),
             tracks: Vec::<EDLTrack>::with_capacity(16),
+            extra_header: Vec::<(String, String)>::default(),
+            unknown_sections: Vec::<(String, Vec<String>)>::default(),
             flags: EDLSESSION_FLAG_DEFAULT,
         }
     }
 
+    pub fn builder() -> EDLSessionBuilder {
+        EDLSessionBuilder::default()
+    }
+
+    /// Parses the Protools EDL file at `input_path`, detecting its encoding
+    /// from a byte order mark and falling back to UTF-8
+    ///
+    /// This is shorthand for `EDLParser::parse_auto`; use `EDLParser` to
+    /// choose the encoding or to collect warnings.
+    pub fn from_path(input_path: &str) -> Result<EDLSession, EDLError> {
+        EDLParser::parse_auto(input_path)
+    }
+
+    /// Inserts `track` at `index`, or appends it when `index` is past the
+    /// end of the track list, and increments `num_audio_tracks`
+    pub fn insert_track_at(&mut self, index: usize, track: EDLTrack) {
+        let index = index.min(self.tracks.len());
+        self.tracks.insert(index, track);
+        self.num_audio_tracks += 1;
+    }
+
+    /// Returns the first track named `name`
+    ///
+    /// Protools does not require track names to be unique, so later tracks
+    /// with the same name are not reachable through this method. `EDLParser`
+    /// reports such tracks as `EDLWarning::DuplicateTrackName`.
+    pub fn track(&self, name: &str) -> Option<&EDLTrack> {
+        self.tracks.iter().find(|track| track.name == name)
+    }
+
+    /// Mutable variant of `EDLSession::track`
+    pub fn track_mut(&mut self, name: &str) -> Option<&mut EDLTrack> {
+        self.tracks.iter_mut().find(|track| track.name == name)
+    }
+
+    /// Returns the marker whose `#` column is `id`
+    pub fn marker(&self, id: u32) -> Option<&EDLMarker> {
+        self.markers.iter().find(|marker| marker.id == id)
+    }
+
+    /// Mutable variant of `EDLSession::marker`
+    pub fn marker_mut(&mut self, id: u32) -> Option<&mut EDLMarker> {
+        self.markers.iter_mut().find(|marker| marker.id == id)
+    }
+
+    /// The position of `marker` in samples from the session's
+    /// `start_timecode`
+    ///
+    /// For `Samples` markers this is the marker's `time_reference`. Other
+    /// markers are positioned from their location, using the session's
+    /// sample rate and frame rate, with Feet+Frames counted at 16 frames per
+    /// foot. Bars|Beats locations depend on the session's tempo map, which
+    /// the EDL does not carry, so those markers fall back to their
+    /// `time_reference`.
+    pub fn marker_sample_position(&self, marker: &EDLMarker) -> u64 {
+        const FEET_FRAMES_PER_FOOT: u64 = 16;
+
+        match (&marker.unit, &marker.location) {
+            (EDLUnit::Samples, _) | (_, EDLMarkerLocation::BarsBeats { .. }) => marker.time_reference as u64,
+            (_, EDLMarkerLocation::Samples(samples)) => *samples,
+            (_, EDLMarkerLocation::Timecode(timecode)) => self.frames_to_samples(self.relative_time(*timecode).to_frames()),
+            (_, EDLMarkerLocation::FeetFrames { feet, frames }) => self.frames_to_samples(*feet as u64 * FEET_FRAMES_PER_FOOT + *frames as u64),
+            (_, EDLMarkerLocation::MinutesSeconds { minutes, milliseconds }) => {
+                let milliseconds = *minutes as u64 * 60_000 + *milliseconds as u64;
+                (milliseconds as f64 / 1000.0 * self.sample_rate.as_f64()).round() as u64
+            },
+        }
+    }
+
+    /// Returns `false` if `marker` is a `Samples` marker whose timecode
+    /// location and `time_reference` are more than one frame apart
+    ///
+    /// Markers in other units, and `Samples` markers located by a plain
+    /// sample count, carry only one position and are always consistent.
+    pub fn has_consistent_marker_reference(&self, marker: &EDLMarker) -> bool {
+        match (&marker.unit, &marker.location) {
+            (EDLUnit::Samples, EDLMarkerLocation::Timecode(timecode)) => {
+                let location_samples = self.frames_to_samples(self.relative_time(*timecode).to_frames());
+                location_samples.abs_diff(marker.time_reference as u64) <= self.frames_to_samples(1)
+            },
+            _ => true,
+        }
+    }
+
+    /// Stably sorts the markers by `EDLSession::marker_sample_position`,
+    /// so markers in any unit are ordered by where they fall in the session
+    pub fn sort_markers(&mut self) {
+        let mut markers = std::mem::take(&mut self.markers);
+        markers.sort_by_cached_key(|marker| self.marker_sample_position(marker));
+        self.markers = markers;
+    }
+
+    /// The number of samples in `frames` frames at the session's frame rate
+    /// and sample rate
+    fn frames_to_samples(&self, frames: u64) -> u64 {
+        let (fps_numerator, fps_denominator) = self.fps.as_rational();
+        (frames as f64 * fps_denominator as f64 / fps_numerator as f64 * self.sample_rate.as_f64()).round() as u64
+    }
+
+    /// `timecode` relative to the session's `start_timecode`, e.g.
+    /// `00:00:05:00` for an event at `01:00:05:00` in a session starting at
+    /// `01:00:00:00`
+    ///
+    /// Timecodes before the session start are clamped to zero.
+    pub fn relative_time(&self, timecode: Timecode) -> Timecode {
+        timecode - self.start_timecode
+    }
+
+    /// The length of the timeline, measured from the session's
+    /// `start_timecode` (not from zero) to the latest `time_out` of any event
+    ///
+    /// A session without events, or whose events all end before the session
+    /// start, has a zero duration. Events may be at different frame rates,
+    /// as the latest `time_out` is found by its tick count at the session's
+    /// frame rate.
+    pub fn duration(&self) -> Timecode {
+        self.events()
+            .map(|(_, event)| event.time_out)
+            .max_by_key(|time_out| time_out.ticks_at(self.fps))
+            .map_or(Timecode::with_fps(self.fps), |time_out| self.relative_time(time_out))
+    }
+
+    /// Iterates over every event in the session by reference, paired with
+    /// the track it belongs to, in track order
+    pub fn events(&self) -> impl Iterator<Item = (&EDLTrack, &EDLTrackEvent)> {
+        self.tracks
+            .iter()
+            .flat_map(|track| track.events.iter().map(move |event| (track, event)))
+    }
+
+    /// Calls `f` on every event in the session, across all tracks, in
+    /// `time_in` order, which may mix frame rates
+    ///
+    /// Events that start at the same time are visited in track order, then
+    /// in their order within the track.
+    pub fn for_each_event_mut_sorted(&mut self, mut f: impl FnMut(&mut EDLTrackEvent)) {
+        let mut event_indices = self.tracks
+            .iter()
+            .enumerate()
+            .flat_map(|(track_index, track)| (0..track.events.len()).map(move |event_index| (track_index, event_index)))
+            .collect::<Vec<_>>();
+
+        // Start times are compared as tick counts at the session's frame
+        // rate, so events at other frame rates are visited by when they
+        // start rather than by their timecode groups
+        event_indices.sort_by_key(|&(track_index, event_index)| self.tracks[track_index].events[event_index].time_in.ticks_at(self.fps));
+
+        for (track_index, event_index) in event_indices {
+            f(&mut self.tracks[track_index].events[event_index]);
+        }
+    }
+
+    /// Sorts the events of every track with `EDLTrack::sort_events`
+    pub fn sort_events(&mut self) {
+        for track in self.tracks.iter_mut() {
+            track.sort_events();
+        }
+    }
+
+    /// The sum of the distinct channel counts of every track
+    pub fn total_channels(&self) -> usize {
+        self.tracks.iter().map(|track| track.channels().len()).sum()
+    }
+
+    /// Reports the distinct frame rates inferred from the delimiters of the
+    /// session's timecodes, in the order they were first seen
+    ///
+    /// Every timecode is parsed at the session's frame rate, but the parser
+    /// accepts both `:` and `;` delimiters, so a file mixing drop-frame and
+    /// non-drop-frame timecodes reports more than one rate here.
+    pub fn distinct_frame_rates_seen(&self) -> Vec<FrameRate> {
+        let event_timecodes = self.events()
+            .flat_map(|(_, event)| {
+                let timestamp = (event.timestamp != Timecode::with_fps(event.timestamp.frame_rate())).then_some(event.timestamp);
+                [Some(event.time_in), Some(event.time_out), timestamp]
+            })
+            .flatten();
+
+        let marker_timecodes = self.markers
+            .iter()
+            .filter_map(|marker| marker.location.as_timecode().copied());
+
+        let mut frame_rates = Vec::<FrameRate>::new();
+        for timecode in std::iter::once(self.start_timecode).chain(event_timecodes).chain(marker_timecodes) {
+            let frame_rate = timecode.inferred_frame_rate();
+            if !frame_rates.contains(&frame_rate) {
+                frame_rates.push(frame_rate);
+            }
+        }
+
+        frame_rates
+    }
+
+    /// Checks the header's track, clip and file totals against the parsed
+    /// tracks, online clips and online files
+    pub fn validate(&self) -> Result<(), Vec<EDLValidationWarning>> {
+        let mut warnings = Vec::<EDLValidationWarning>::new();
+
+        if self.num_audio_tracks as usize != self.tracks.len() {
+            warnings.push(EDLValidationWarning::TrackCountMismatch { expected: self.num_audio_tracks, found: self.tracks.len() });
+        }
+
+        if self.num_audio_clips as usize != self.files.online_clips.len() {
+            warnings.push(EDLValidationWarning::ClipCountMismatch { expected: self.num_audio_clips, found: self.files.online_clips.len() });
+        }
+
+        if self.num_audio_files as usize != self.files.online_files.len() {
+            warnings.push(EDLValidationWarning::FileCountMismatch { expected: self.num_audio_files, found: self.files.online_files.len() });
+        }
+
+        if warnings.is_empty() { Ok(()) } else { Err(warnings) }
+    }
+
+    /// A hash over the semantic content of the session, for keying caches
+    /// of parsed results
+    ///
+    /// The order of tracks, markers, plugins, files and clips does not
+    /// affect the hash, so sessions that only differ in listing order hash
+    /// equally. Events are hashed in their order within each track.
+    ///
+    /// The hashing algorithm is a fixed 64-bit FNV-1a, but fields are fed
+    /// through their std `Hash` impls, so the value is only guaranteed to be
+    /// stable for a given crate version.
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = EDLContentHasher::default();
+
+        self.name.hash(&mut hasher);
+        self.sample_rate.hash(&mut hasher);
+        self.bit_depth.hash(&mut hasher);
+        self.start_timecode.hash(&mut hasher);
+        self.fps.hash(&mut hasher);
+        self.num_audio_tracks.hash(&mut hasher);
+        self.num_audio_clips.hash(&mut hasher);
+        self.num_audio_files.hash(&mut hasher);
+        self.extra_header.hash(&mut hasher);
+        self.unknown_sections.hash(&mut hasher);
+
+        hash_unordered(&self.files.online_files, &mut hasher);
+        hash_unordered(&self.files.offline_files, &mut hasher);
+        hash_unordered(&self.files.online_clips, &mut hasher);
+        hash_unordered(&self.markers, &mut hasher);
+        hash_unordered(&self.plugins, &mut hasher);
+        hash_unordered(&self.tracks, &mut hasher);
+
+        hasher.finish()
+    }
+
     pub fn check_flag(&self, flag: u64) -> bool {
         self.flags & flag == flag
     }
@@ -66,11 +326,574 @@ impl EDLSession {
     }
 }
 
+/// A short human readable summary of the session, e.g. for CLI inspection
+impl std::fmt::Display for EDLSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Session: {}", self.name)?;
+        writeln!(f, "Format: {} Hz, {}, {} fps, starting at {}", self.sample_rate.as_hz(), self.bit_depth, self.fps, self.start_timecode)?;
+        writeln!(
+            f,
+            "Contents: {} tracks, {} markers, {} plug-ins, {} online files, {} offline files, {} clips",
+            self.tracks.len(),
+            self.markers.len(),
+            self.plugins.len(),
+            self.files.online_files.len(),
+            self.files.offline_files.len(),
+            self.files.online_clips.len(),
+        )?;
+        write!(f, "Duration: {}", self.duration())
+    }
+}
+
+/// Sessions are equal when their header fields and listings are equal, in
+/// order
+///
+/// The session flags only record which sections were present in a parsed
+/// file, so like `EDLSession::content_hash` they are left out, and a parsed
+/// session can equal one built with `EDLSessionBuilder`.
+impl PartialEq for EDLSession {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+        && self.sample_rate == other.sample_rate
+        && self.bit_depth == other.bit_depth
+        && self.start_timecode == other.start_timecode
+        && self.fps == other.fps
+        && self.num_audio_tracks == other.num_audio_tracks
+        && self.num_audio_clips == other.num_audio_clips
+        && self.num_audio_files == other.num_audio_files
+        && self.files == other.files
+        && self.markers == other.markers
+        && self.plugins == other.plugins
+        && self.tracks == other.tracks
+        && self.extra_header == other.extra_header
+        && self.unknown_sections == other.unknown_sections
+    }
+}
+
+impl Eq for EDLSession {}
+
+impl IntoIterator for EDLSession {
+    type Item = EDLTrack;
+    type IntoIter = std::vec::IntoIter<EDLTrack>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tracks.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a EDLSession {
+    type Item = &'a EDLTrack;
+    type IntoIter = std::slice::Iter<'a, EDLTrack>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tracks.iter()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLSessionBuilder` Implementation --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// Builds an `EDLSession` in code, e.g. for serializing with
+/// `EDLSession::to_edl_string`
+///
+/// The header's track, clip and file totals are derived from what was
+/// added, and `EDLSESSION_FLAG_CONTAINS_PLUGIN` is set if any plugin was
+/// added. Unless `start_timecode` is given, the session starts at zero at
+/// the session's frame rate.
+#[derive(Debug, Default)]
+pub struct EDLSessionBuilder {
+    session: EDLSession,
+    start_timecode: Option<Timecode>,
+}
+
+impl EDLSessionBuilder {
+    pub fn name(mut self, name: &str) -> Self {
+        self.session.name = name.to_string();
+        self
+    }
+
+    pub fn sample_rate(mut self, sample_rate: SampleRate) -> Self {
+        self.session.sample_rate = sample_rate;
+        self
+    }
+
+    pub fn bit_depth(mut self, bit_depth: BitDepth) -> Self {
+        self.session.bit_depth = bit_depth;
+        self
+    }
+
+    pub fn fps(mut self, fps: FrameRate) -> Self {
+        self.session.fps = fps;
+        self
+    }
+
+    pub fn start_timecode(mut self, start_timecode: Timecode) -> Self {
+        self.start_timecode = Some(start_timecode);
+        self
+    }
+
+    pub fn add_track(mut self, track: EDLTrack) -> Self {
+        self.session.tracks.push(track);
+        self
+    }
+
+    pub fn add_marker(mut self, marker: EDLMarker) -> Self {
+        self.session.markers.push(marker);
+        self
+    }
+
+    pub fn add_plugin(mut self, plugin: EDLPlugin) -> Self {
+        self.session.plugins.push(plugin);
+        self
+    }
+
+    pub fn add_online_file(mut self, media_file: EDLMediaFile) -> Self {
+        self.session.files.online_files.push(media_file);
+        self
+    }
+
+    pub fn add_offline_file(mut self, media_file: EDLMediaFile) -> Self {
+        self.session.files.offline_files.push(media_file);
+        self
+    }
+
+    pub fn add_online_clip(mut self, clip: EDLClip) -> Self {
+        self.session.files.online_clips.push(clip);
+        self
+    }
+
+    pub fn build(self) -> EDLSession {
+        let mut session = self.session;
+
+        session.start_timecode = self.start_timecode.unwrap_or_else(|| Timecode::with_fps(session.fps));
+        session.num_audio_tracks = session.tracks.len() as u32;
+        session.num_audio_clips = session.files.online_clips.len() as u32;
+        session.num_audio_files = session.files.online_files.len() as u32;
+
+        if !session.plugins.is_empty() {
+            session.set_flag(EDLSESSION_FLAG_CONTAINS_PLUGIN);
+        }
+
+        session
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLSession` Helpers --
+//
+///////////////////////////////////////////////////////////////////////////
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A 64-bit FNV-1a `Hasher` for `EDLSession::content_hash`
+///
+/// Unlike `std::collections::hash_map::DefaultHasher`, the algorithm is
+/// fixed. Integers are written as little-endian bytes, with `usize` and
+/// `isize` widened to 64 bits, so the output does not depend on the platform.
+struct EDLContentHasher(u64);
+
+impl Default for EDLContentHasher {
+    fn default() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for EDLContentHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) { self.write(&[i]) }
+    fn write_u16(&mut self, i: u16) { self.write(&i.to_le_bytes()) }
+    fn write_u32(&mut self, i: u32) { self.write(&i.to_le_bytes()) }
+    fn write_u64(&mut self, i: u64) { self.write(&i.to_le_bytes()) }
+    fn write_u128(&mut self, i: u128) { self.write(&i.to_le_bytes()) }
+    fn write_usize(&mut self, i: usize) { self.write_u64(i as u64) }
+    fn write_i8(&mut self, i: i8) { self.write_u8(i as u8) }
+    fn write_i16(&mut self, i: i16) { self.write_u16(i as u16) }
+    fn write_i32(&mut self, i: i32) { self.write_u32(i as u32) }
+    fn write_i64(&mut self, i: i64) { self.write_u64(i as u64) }
+    fn write_i128(&mut self, i: i128) { self.write_u128(i as u128) }
+    fn write_isize(&mut self, i: isize) { self.write_u64(i as i64 as u64) }
+}
+
+/// Feeds `items` into `hasher` independently of their order by hashing
+/// each item on its own and combining the sorted item hashes
+fn hash_unordered<T: Hash, H: Hasher>(items: &[T], hasher: &mut H) {
+    let mut item_hashes = items
+        .iter()
+        .map(|item| {
+            let mut item_hasher = EDLContentHasher::default();
+            item.hash(&mut item_hasher);
+            item_hasher.finish()
+        })
+        .collect::<Vec<_>>();
+
+    item_hashes.sort_unstable();
+    item_hashes.hash(hasher);
+}
+
 ///////////////////////////////////////////////////////////////////////////
 //
 //  -- @SECTION `EDLSession` Flags --
 //
 ///////////////////////////////////////////////////////////////////////////
 
+// The `CONTAINS` flags are set by `EDLParser` when the EDL had the
+// section's banner, even if the section had no rows, so that
+// `EDLSession::write_edl` can write empty sections back out. The exception
+// is `EDLSESSION_FLAG_CONTAINS_PLUGIN`, which is only set when at least one
+// plugin was parsed
+
 pub const EDLSESSION_FLAG_DEFAULT: u64 = 0;
 pub const EDLSESSION_FLAG_CONTAINS_PLUGIN: u64 = 1 << 1;
+pub const EDLSESSION_FLAG_CONTAINS_ONLINE_FILES: u64 = 1 << 2;
+pub const EDLSESSION_FLAG_CONTAINS_OFFLINE_FILES: u64 = 1 << 3;
+pub const EDLSESSION_FLAG_CONTAINS_ONLINE_CLIPS: u64 = 1 << 4;
+pub const EDLSESSION_FLAG_CONTAINS_TRACKS: u64 = 1 << 5;
+pub const EDLSESSION_FLAG_CONTAINS_MARKERS: u64 = 1 << 6;
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLSession` Unit Tests --
+//
+///////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE_BASIC_SESSION: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/basic_session.txt");
+
+    fn basic_session() -> EDLSession {
+        EDLParser::parse(FIXTURE_BASIC_SESSION, encoding_rs::UTF_8).expect("basic session fixture should parse")
+    }
+
+    fn timecode(tc_string: &str) -> Timecode {
+        Timecode::from_str(tc_string, FrameRate::Fps25).expect("test timecode should be valid")
+    }
+
+    fn event(name: &str, time_in: &str, time_out: &str) -> EDLTrackEvent {
+        let mut event = EDLTrackEvent::default();
+        event.channel = 1;
+        event.event = 1;
+        event.name = name.to_string();
+        event.time_in = timecode(time_in);
+        event.time_out = timecode(time_out);
+        event.duration = event.time_out - event.time_in;
+        event.timestamp = timecode(time_in);
+        event.set_flag(EDLEVENT_FLAG_HAS_TIMESTAMP);
+        event
+    }
+
+    /// `event` with its times read at `fps` rather than 25fps
+    fn event_at(fps: FrameRate, name: &str, time_in: &str, time_out: &str) -> EDLTrackEvent {
+        let mut event = event(name, "00:00:00:00", "00:00:00:00");
+        event.time_in = Timecode::from_str(time_in, fps).expect("test timecode should be valid");
+        event.time_out = Timecode::from_str(time_out, fps).expect("test timecode should be valid");
+        event.duration = event.time_out - event.time_in;
+        event.timestamp = event.time_in;
+        event
+    }
+
+    fn session_with_tracks() -> EDLSession {
+        let mut session = EDLSession::new();
+
+        let mut dialog = EDLTrack::with_name("DIA");
+        dialog.events.push(event("A", "01:00:00:00", "01:00:02:00"));
+        dialog.events.push(event("C", "01:00:06:00", "01:00:08:00"));
+
+        let mut music = EDLTrack::with_name("MX");
+        music.events.push(event("B", "01:00:03:00", "01:00:05:00"));
+
+        session.tracks.push(dialog);
+        session.tracks.push(music);
+        session
+    }
+
+    #[test]
+    fn into_iter_tracks_and_events() {
+        let session = session_with_tracks();
+
+        let mut event_names = Vec::new();
+        for track in &session {
+            for event in track {
+                event_names.push(event.name.as_str());
+            }
+        }
+        assert_eq!(event_names, ["A", "C", "B"]);
+
+        let track_names = session_with_tracks().into_iter().map(|track| track.name).collect::<Vec<_>>();
+        assert_eq!(track_names, ["DIA", "MX"]);
+
+        let events = session.into_iter().flatten().map(|event| event.name).collect::<Vec<_>>();
+        assert_eq!(events, ["A", "C", "B"]);
+    }
+
+    #[test]
+    fn for_each_event_mut_sorted_order() {
+        let mut session = session_with_tracks();
+        let mut visited = Vec::new();
+        let mut ripple = 0;
+
+        session.for_each_event_mut_sorted(|event| {
+            visited.push(event.name.clone());
+            event.shift_signed(ripple);
+            ripple += 25;
+        });
+
+        assert_eq!(visited, ["A", "B", "C"]);
+        assert_eq!(session.tracks[0].events[0].time_in, timecode("01:00:00:00"));
+        assert_eq!(session.tracks[1].events[0].time_in, timecode("01:00:04:00"));
+        assert_eq!(session.tracks[0].events[1].time_in, timecode("01:00:08:00"));
+
+        // 01:00:04:22 at 30fps is 4.733s, before 01:00:04:20 at 25fps, 4.8s,
+        // though its frames group is larger
+        let mut mixed_rates = session_with_tracks();
+        mixed_rates.tracks[1].events.push(event_at(FrameRate::Fps30(false), "D", "01:00:04:22", "01:00:05:00"));
+        mixed_rates.tracks[0].events.push(event("E", "01:00:04:20", "01:00:05:00"));
+
+        let mut visited = Vec::new();
+        mixed_rates.for_each_event_mut_sorted(|event| visited.push(event.name.clone()));
+        assert_eq!(visited, ["A", "B", "D", "E", "C"]);
+    }
+
+    #[test]
+    fn insert_track_at_front_and_past_end() {
+        let mut session = session_with_tracks();
+        session.num_audio_tracks = 2;
+
+        session.insert_track_at(0, EDLTrack::with_name("FX"));
+        session.insert_track_at(100, EDLTrack::with_name("VO"));
+
+        let names = session.tracks.iter().map(|track| track.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["FX", "DIA", "MX", "VO"]);
+        assert_eq!(session.num_audio_tracks, 4);
+    }
+
+    #[test]
+    fn content_hasher_is_fnv1a() {
+        let mut hasher = EDLContentHasher::default();
+        assert_eq!(hasher.finish(), FNV_OFFSET_BASIS);
+
+        hasher.write(b"a");
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn content_hash_ignores_track_order() {
+        let session = session_with_tracks();
+
+        let mut reordered = session_with_tracks();
+        reordered.tracks.reverse();
+        assert_eq!(reordered.content_hash(), session.content_hash());
+
+        let mut edited = session_with_tracks();
+        edited.tracks[0].events[0].shift_signed(1);
+        assert_ne!(edited.content_hash(), session.content_hash());
+    }
+
+    #[test]
+    fn events_flattens_tracks_by_reference() {
+        let session = session_with_tracks();
+        let total_events = session.tracks.iter().map(|track| track.events.len()).sum::<usize>();
+
+        assert_eq!(session.events().count(), total_events);
+
+        let names = session.events().map(|(track, event)| (track.name.as_str(), event.name.as_str())).collect::<Vec<_>>();
+        assert_eq!(names, [("DIA", "A"), ("DIA", "C"), ("MX", "B")]);
+    }
+
+    #[test]
+    fn sort_events_per_track() {
+        let mut session = session_with_tracks();
+        session.tracks[0].events.reverse();
+        session.tracks[1].events.insert(0, event("D", "01:00:09:00", "01:00:10:00"));
+
+        session.sort_events();
+        assert_eq!(session.tracks[0].events.iter().map(|event| event.name.as_str()).collect::<Vec<_>>(), ["A", "C"]);
+        assert_eq!(session.tracks[1].events.iter().map(|event| event.name.as_str()).collect::<Vec<_>>(), ["B", "D"]);
+    }
+
+    #[test]
+    fn total_channels() {
+        let mut session = session_with_tracks();
+        assert_eq!(session.total_channels(), 2);
+
+        let mut right = event("B", "01:00:03:00", "01:00:05:00");
+        right.channel = 2;
+        session.tracks[1].events.push(right);
+
+        assert_eq!(session.tracks[1].channels(), [1, 2]);
+        assert_eq!(session.total_channels(), 3);
+    }
+
+    #[test]
+    fn track_and_marker_lookup() {
+        let mut session = session_with_tracks();
+        session.markers.push(EDLMarker { id: 3, name: "Third Act".to_string(), ..EDLMarker::default() });
+
+        assert_eq!(session.track("MX").map(|track| track.events.len()), Some(1));
+        assert!(session.track("FX").is_none());
+        assert_eq!(session.marker(3).map(|marker| marker.name.as_str()), Some("Third Act"));
+        assert!(session.marker(4).is_none());
+
+        session.track_mut("DIA").expect("DIA track should exist").comment = "Production".to_string();
+        session.marker_mut(3).expect("marker 3 should exist").comment = "Pickup".to_string();
+        assert_eq!(session.tracks[0].comment, "Production");
+        assert_eq!(session.markers[0].comment, "Pickup");
+        assert!(session.track_mut("FX").is_none());
+        assert!(session.marker_mut(4).is_none());
+    }
+
+    #[test]
+    fn from_path() {
+        let session = EDLSession::from_path(FIXTURE_BASIC_SESSION).expect("basic session fixture should parse");
+
+        assert_eq!(session, basic_session());
+        assert_eq!(session.tracks.len(), 2);
+        assert!(matches!(EDLSession::from_path("does/not/exist.txt"), Err(EDLError::Io(_))));
+    }
+
+    #[test]
+    fn marker_sample_position() {
+        let session = basic_session();
+
+        let samples_marker = session.marker(2).expect("marker 2 should exist");
+        assert_eq!(samples_marker.unit, EDLUnit::Samples);
+        assert_eq!(session.marker_sample_position(samples_marker), 288000);
+
+        let timecode_marker = EDLMarker { unit: EDLUnit::Timecode, time_reference: 0, ..samples_marker.clone() };
+        assert_eq!(session.marker_sample_position(&timecode_marker), session.marker_sample_position(samples_marker));
+
+        let minutes_marker = EDLMarker {
+            unit: EDLUnit::MinutesSeconds,
+            location: EDLMarkerLocation::MinutesSeconds { minutes: 0, milliseconds: 6000 },
+            ..EDLMarker::default()
+        };
+        assert_eq!(session.marker_sample_position(&minutes_marker), 288000);
+
+        let feet_marker = EDLMarker {
+            unit: EDLUnit::FeetFrames,
+            location: EDLMarkerLocation::FeetFrames { feet: 9, frames: 6 },
+            ..EDLMarker::default()
+        };
+        assert_eq!(session.marker_sample_position(&feet_marker), 288000);
+    }
+
+    #[test]
+    fn has_consistent_marker_reference() {
+        let session = basic_session();
+
+        let samples_marker = session.marker(2).expect("marker 2 should exist");
+        assert!(session.has_consistent_marker_reference(samples_marker));
+        assert!(session.has_consistent_marker_reference(&EDLMarker { time_reference: 288000 + 1920, ..samples_marker.clone() }));
+        assert!(!session.has_consistent_marker_reference(&EDLMarker { time_reference: 288000 + 1921, ..samples_marker.clone() }));
+        assert!(session.has_consistent_marker_reference(&EDLMarker { unit: EDLUnit::Timecode, time_reference: 0, ..samples_marker.clone() }));
+    }
+
+    #[test]
+    fn validate_header_totals() {
+        let text = std::fs::read_to_string(FIXTURE_BASIC_SESSION).expect("basic session fixture should be readable");
+        let (session, _) = EDLParser::parse_bytes(text.as_bytes(), encoding_rs::UTF_8).expect("basic session fixture should parse");
+        assert_eq!(session.validate(), Ok(()));
+
+        let wrong_header = text.replacen("# OF AUDIO TRACKS:\t2", "# OF AUDIO TRACKS:\t12", 1);
+        let (session, _) = EDLParser::parse_bytes(wrong_header.as_bytes(), encoding_rs::UTF_8).expect("mismatched header should still parse");
+        assert_eq!(session.validate(), Err(vec![EDLValidationWarning::TrackCountMismatch { expected: 12, found: 2 }]));
+    }
+
+    #[test]
+    fn builder_builds_serializable_session() {
+        let mut dialog = EDLTrack::with_name("DIA");
+        dialog.events.push(event("Dialog_01-01", "01:00:00:00", "01:00:05:00"));
+
+        let session = EDLSession::builder()
+            .name("Built Session")
+            .sample_rate(SampleRate::Khz48)
+            .bit_depth(BitDepth::Bit24)
+            .fps(FrameRate::Fps25)
+            .start_timecode(timecode("01:00:00:00"))
+            .add_online_file(EDLMediaFile { file_name: "Dialog_01.wav".to_string(), location: Some("Audio Files:".to_string()), ..EDLMediaFile::default() })
+            .add_online_clip(EDLClip { clip_name: "Dialog_01-01".to_string(), source_file: "Dialog_01.wav".to_string(), ..EDLClip::default() })
+            .add_plugin(EDLPlugin { manufacturer: "Avid".to_string(), name: "EQ3 7-Band".to_string(), instances: EDLPluginInstance { active: 1, total: 1 }, ..EDLPlugin::default() })
+            .add_track(dialog)
+            .add_marker(EDLMarker { id: 1, name: "First Act".to_string(), location: EDLMarkerLocation::Timecode(timecode("01:00:00:00")), ..EDLMarker::default() })
+            .build();
+
+        assert!(session.check_flag(EDLSESSION_FLAG_CONTAINS_PLUGIN));
+        assert_eq!(session.validate(), Ok(()));
+
+        let (reparsed, warnings) = EDLParser::parse_bytes(session.to_edl_string().as_bytes(), encoding_rs::UTF_8).expect("built session should serialize to a parsable EDL");
+        assert!(warnings.is_empty());
+        assert_eq!(reparsed.content_hash(), session.content_hash());
+        assert!(!EDLSession::builder().build().check_flag(EDLSESSION_FLAG_CONTAINS_PLUGIN));
+    }
+
+    #[test]
+    fn duration_from_session_start() {
+        let mut session = session_with_tracks();
+        session.start_timecode = timecode("01:00:00:00");
+        assert_eq!(session.duration(), timecode("00:00:08:00"));
+
+        session.tracks[1].events[0].time_out = timecode("01:00:12:10");
+        assert_eq!(session.duration(), timecode("00:00:12:10"));
+        assert_eq!(session.tracks[1].duration(), timecode("00:00:09:10"));
+        assert_eq!(EDLSession::new().duration(), Timecode::with_fps(FrameRate::default()));
+
+        // 01:00:04:28 at 30fps ends at 4.933s, before 01:00:04:24 at 25fps
+        let mut mixed_rates = session_with_tracks();
+        mixed_rates.start_timecode = timecode("01:00:00:00");
+        mixed_rates.tracks[0].events = vec![event("E", "01:00:04:20", "01:00:04:24")];
+        mixed_rates.tracks[1].events = vec![event_at(FrameRate::Fps30(false), "D", "01:00:04:22", "01:00:04:28")];
+        assert_eq!(mixed_rates.duration(), timecode("00:00:04:24"));
+    }
+
+    #[test]
+    fn relative_time_from_session_start() {
+        let mut session = session_with_tracks();
+        session.start_timecode = timecode("01:00:00:00");
+
+        assert_eq!(session.relative_time(timecode("01:00:05:00")), timecode("00:00:05:00"));
+        assert_eq!(session.relative_time(timecode("01:00:00:00")), timecode("00:00:00:00"));
+        assert_eq!(session.relative_time(timecode("00:59:59:24")), timecode("00:00:00:00"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_round_trip() {
+        let session = basic_session();
+
+        let json = serde_json::to_string(&session).expect("session should serialize to JSON");
+        assert!(json.contains("\"fps\":\"25 Frame\""));
+        assert!(json.contains("\"sample_rate\":\"48000.000000\""));
+
+        let reloaded = serde_json::from_str::<EDLSession>(&json).expect("session should deserialize from JSON");
+        assert_eq!(reloaded.content_hash(), session.content_hash());
+        assert_eq!(reloaded.check_flag(EDLSESSION_FLAG_CONTAINS_PLUGIN), session.check_flag(EDLSESSION_FLAG_CONTAINS_PLUGIN));
+        assert_eq!(reloaded.to_edl_string(), session.to_edl_string());
+    }
+
+    #[test]
+    fn display_summary() {
+        let session = basic_session();
+        let summary = session.to_string();
+
+        assert!(summary.contains("Timeline Kit Demo"));
+        assert!(summary.contains("2 tracks"));
+        assert!(summary.contains("Duration: 00:00:20:00"));
+        assert_eq!(summary.lines().count(), 4);
+    }
+}
@@ -1,48 +1,165 @@
 // Copyright (C) Stefan Olivier
 // <https://stefanolivier.com>
 
+use std::path::{Path, PathBuf};
+
 use crate::edl::protools::*;
 use crate::chrono::{
+    TimeRange,
     Timecode,
 };
 use crate::format::{
+    FormatParseError,
     FrameRate,
 };
 
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLSourceLine` Implementation --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// The 1-based line of the input file a value was parsed from, or `None`
+/// for values that were not parsed by `EDLParser`
+///
+/// Source lines are bookkeeping rather than content, so every
+/// `EDLSourceLine` compares equal to every other and hashes to nothing,
+/// leaving the equality and hashes of the types that carry one unchanged.
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct EDLSourceLine(pub Option<usize>);
+
+impl PartialEq for EDLSourceLine {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for EDLSourceLine {}
+
+impl PartialOrd for EDLSourceLine {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EDLSourceLine {
+    fn cmp(&self, _: &Self) -> std::cmp::Ordering {
+        std::cmp::Ordering::Equal
+    }
+}
+
+impl std::hash::Hash for EDLSourceLine {
+    fn hash<H: std::hash::Hasher>(&self, _: &mut H) {}
+}
+
 ///////////////////////////////////////////////////////////////////////////
 //
 //  -- @SECTION `EDLMediaFile` Implementation --
 //
 ///////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Default, PartialEq, PartialOrd, Ord, Clone, Eq)]
+#[derive(Debug, Default, PartialEq, PartialOrd, Ord, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EDLMediaFile {
     pub file_name: String,
-    pub location: String,
+    /// `None` when the Location column is missing or empty, which is
+    /// expected for offline files but usually means an online file needs
+    /// relinking
+    pub location: Option<String>,
+    pub source_line: EDLSourceLine,
 }
 
+/// Reads the online and offline files tables, whose columns are
+/// `Filename` and `Location`
 impl ParseTable<Self, ()> for EDLMediaFile {
     const TABLE_TOTAL_COLUMNS: usize = 2;
-    fn parse_table(table_data: &[String], _: ()) -> Option<Vec<Self>> {
+
+    /// Rows without the Location column are accepted, since offline files
+    /// are sometimes listed by name alone
+    fn is_table_row(header_row: &str, line: &str) -> bool {
+        let total_columns = line.split('\t').count();
+        total_columns == Self::expected_columns(header_row) || total_columns == Self::TABLE_TOTAL_COLUMNS - 1
+    }
+
+    fn parse_table<S: AsRef<str>>(table_data: &[S], _: ()) -> Result<Vec<Self>, EDLError> {
         let mut edl_media = Vec::<Self>::with_capacity(table_data.len());
 
         for (i, line) in table_data.iter().enumerate() {
-            let parts = line.split("\t").into_iter().collect::<Vec<_>>();
-            if parts.len() == Self::TABLE_TOTAL_COLUMNS && i > 0 {
+            let parts = line.as_ref().split('\t').collect::<Vec<_>>();
+            if (parts.len() == Self::TABLE_TOTAL_COLUMNS || parts.len() == Self::TABLE_TOTAL_COLUMNS - 1) && i > 0 {
+                let location = parts.get(1).map(|location| location.trim()).filter(|location| !location.is_empty());
                 edl_media.push(
                     Self {
                         file_name: parts[0].trim().to_string(),
-                        location: parts[1].trim().to_string(),
+                        location: location.map(str::to_string),
+                        ..Self::default()
                     }
                 );
             }
 
-            else { /* TODO: Report? */ }
+            else { /* Skipped rows are reported by `EDLParser` as `EDLWarning::SkippedRow` */ }
         }
         
-        if edl_media.len() > 0 { return Some(edl_media); }
+        Ok(edl_media)
+    }
+}
+
+impl EDLMediaFile {
+    /// The file's path, joined from its location and file name
+    ///
+    /// Locations are split on `\` and `/` when they contain either, so a
+    /// Windows location such as `D:\Sessions\Audio Files\` is read as a
+    /// Windows path on any platform, and otherwise on `:` as in a Mac
+    /// location such as `Macintosh HD:Sessions:Audio Files:`, whose first
+    /// component is the volume name. The path is built with the current
+    /// platform's separator, and the file name is only appended when the
+    /// location does not already end with it.
+    pub fn path(&self) -> PathBuf {
+        let location = self.location.as_deref().unwrap_or("");
+        let is_slash_delimited = location.contains(['\\', '/']);
+        let separators: &[char] = if is_slash_delimited { &['\\', '/'] } else { &[':'] };
+
+        let mut components = location.split(separators).filter(|component| !component.is_empty()).collect::<Vec<_>>();
+        if components.last() != Some(&self.file_name.as_str()) {
+            components.push(self.file_name.as_str());
+        }
 
-        None
+        let mut path = PathBuf::new();
+        if location.starts_with(['\\', '/']) {
+            path.push(std::path::MAIN_SEPARATOR_STR);
+        }
+
+        for (i, component) in components.into_iter().enumerate() {
+            // A drive such as `D:` is pushed with a separator, since `D:`
+            // alone is drive relative on Windows
+            if i == 0 && is_slash_delimited && component.ends_with(':') {
+                path.push(format!("{}{}", component, std::path::MAIN_SEPARATOR));
+            } else {
+                path.push(component);
+            }
+        }
+
+        path
+    }
+
+    /// The file name without its extension, e.g. `Dialog_01` for
+    /// `Dialog_01.wav`
+    pub fn file_stem(&self) -> Option<&str> {
+        Path::new(&self.file_name).file_stem().and_then(|file_stem| file_stem.to_str())
+    }
+
+    pub fn extension(&self) -> Option<&str> {
+        Path::new(&self.file_name).extension().and_then(|extension| extension.to_str())
+    }
+}
+
+impl std::fmt::Display for EDLMediaFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} ({})", self.file_name, location),
+            None => write!(f, "{}", self.file_name),
+        }
     }
 }
 
@@ -52,34 +169,43 @@ impl ParseTable<Self, ()> for EDLMediaFile {
 //
 ///////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Default, PartialEq, PartialOrd, Ord, Clone, Eq)]
+#[derive(Debug, Default, PartialEq, PartialOrd, Ord, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EDLClip {
     pub clip_name: String,
     pub source_file: String,
+    pub source_line: EDLSourceLine,
 }
 
+/// Reads the online clips table, whose columns are `CLIP NAME` and
+/// `Source File`
 impl ParseTable<Self, ()> for EDLClip {
     const TABLE_TOTAL_COLUMNS: usize = 2;
-    fn parse_table(table_data: &[String], _: ()) -> Option<Vec<Self>> {
+    fn parse_table<S: AsRef<str>>(table_data: &[S], _: ()) -> Result<Vec<Self>, EDLError> {
         let mut edl_clip = Vec::<Self>::with_capacity(table_data.len());
 
         for (i, line) in table_data.iter().enumerate() {
-            let parts = line.split("\t").into_iter().collect::<Vec<_>>();
+            let parts = line.as_ref().split('\t').collect::<Vec<_>>();
             if parts.len() == Self::TABLE_TOTAL_COLUMNS && i > 0 {
                 edl_clip.push(
                     Self {
                         clip_name: parts[0].trim().to_string(),
                         source_file: parts[1].trim().to_string(),
+                        ..Self::default()
                     }
                 );
             }
 
-            else { /* TODO: Report? */ }
+            else { /* Skipped rows are reported by `EDLParser` as `EDLWarning::SkippedRow` */ }
         }
         
-        if edl_clip.len() > 0 { return Some(edl_clip); }
+        Ok(edl_clip)
+    }
+}
 
-        None
+impl std::fmt::Display for EDLClip {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} <- {}", self.clip_name, self.source_file)
     }
 }
 
@@ -89,35 +215,27 @@ impl ParseTable<Self, ()> for EDLClip {
 //
 ///////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, PartialEq, PartialOrd, Ord, Clone, Eq)]
+#[derive(Debug, Default, PartialEq, PartialOrd, Ord, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EDLFileList {
     pub online_files: Vec<EDLMediaFile>,
     pub offline_files: Vec<EDLMediaFile>,
     pub online_clips: Vec<EDLClip>,
 }
 
-impl Default for EDLFileList {
-    fn default() -> Self {
-        Self {
-            online_files: Vec::<_>::default(),
-            offline_files: Vec::<_>::default(),
-            online_clips: Vec::<_>::default(),
-        }
-    }
-}
-
 ///////////////////////////////////////////////////////////////////////////
 //
 //  -- @SECTION `EDLTrack` Implementation --
 //
 ///////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Default, PartialEq, PartialOrd, Ord, Clone, Eq)]
+#[derive(Debug, Default, PartialEq, PartialOrd, Ord, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EDLTrack {
     pub name: String,
     pub comment: String,
-    pub delay: u32,
-    pub state: (),
+    pub delay: EDLTrackDelay,
+    pub state: EDLTrackState,
     pub plugins: Vec<String>,
     pub events: Vec<EDLTrackEvent>,
 }
@@ -129,6 +247,237 @@ impl EDLTrack {
             ..Self::default()
         }
     }
+
+    /// Index pairs `(i, j)`, with `i < j`, of events on the same channel
+    /// whose `[time_in, time_out)` ranges intersect
+    ///
+    /// Events that only touch, where one's `time_out` is the other's
+    /// `time_in`, do not overlap, and neither do zero-length events since
+    /// their ranges are empty.
+    pub fn overlaps(&self) -> Vec<(usize, usize)> {
+        let mut overlapping_events = Vec::<(usize, usize)>::new();
+
+        for (i, event) in self.events.iter().enumerate() {
+            for (j, other) in self.events.iter().enumerate().skip(i + 1) {
+                if event.overlaps(other) {
+                    overlapping_events.push((i, j));
+                }
+            }
+        }
+
+        overlapping_events
+    }
+
+    /// The `[time_out, time_in)` ranges on `channel` where no event plays,
+    /// between the first event's `time_in` and the last event's `time_out`
+    ///
+    /// Events are taken in start time order, and a gap only opens after
+    /// the latest `time_out` so far, so overlapping or touching events do
+    /// not produce a gap. Zero-length events are ignored, since their
+    /// ranges are empty.
+    pub fn gaps(&self, channel: u32) -> Vec<(Timecode, Timecode)> {
+        let mut channel_events = self.events.iter()
+            .filter(|event| event.channel == channel && event.time_in < event.time_out)
+            .collect::<Vec<_>>();
+        channel_events.sort_by(|event, other| event.time_in.cmp_time(&other.time_in));
+
+        let mut gaps = Vec::<(Timecode, Timecode)>::new();
+        let mut latest_time_out: Option<Timecode> = None;
+
+        for event in channel_events {
+            if let Some(time_out) = latest_time_out {
+                if event.time_in.cmp_time(&time_out).is_gt() {
+                    gaps.push((time_out, event.time_in));
+                }
+            }
+
+            latest_time_out = Some(latest_time_out.map_or(event.time_out, |time_out| time_out.max(event.time_out)));
+        }
+
+        gaps
+    }
+
+    /// Coalesces events that overlap on the same channel, directly or
+    /// through a chain of overlaps, into the earliest of them, which is
+    /// extended to cover the latest `time_out`
+    ///
+    /// The remaining events keep their relative order.
+    pub fn merge_overlaps(&mut self) {
+        let Some(fps) = self.events.first().map(|event| event.time_in.frame_rate()) else {
+            return;
+        };
+
+        let mut event_indices = (0..self.events.len()).collect::<Vec<_>>();
+        event_indices.sort_by_key(|&i| (self.events[i].channel, self.events[i].time_in.ticks_at(fps), i));
+
+        let mut is_merged = vec![false; self.events.len()];
+        let mut cluster: Option<usize> = None;
+
+        for i in event_indices {
+            match cluster {
+                Some(c) if self.events[c].overlaps(&self.events[i]) => {
+                    // The later time_out is converted to the cluster event's
+                    // frame rate, so the merged event keeps a single rate
+                    let cluster_fps = self.events[c].time_in.frame_rate();
+                    let time_out_ticks = self.events[i].time_out.ticks_at(cluster_fps);
+                    if time_out_ticks > self.events[c].time_out.ticks_at(cluster_fps) {
+                        self.events[c].time_out = Timecode::from_ticks(time_out_ticks, cluster_fps);
+                        self.events[c].duration = self.events[c].time_out - self.events[c].time_in;
+                    }
+                    is_merged[i] = true;
+                },
+                _ if self.events[i].time_in.ticks_at(fps) < self.events[i].time_out.ticks_at(fps) => cluster = Some(i),
+                _ => {},
+            }
+        }
+
+        let mut is_merged = is_merged.into_iter();
+        self.events.retain(|_| !is_merged.next().unwrap_or(false));
+    }
+
+    /// The span from the earliest `time_in` to the latest `time_out` of the
+    /// track's events, which is zero for a track without events
+    ///
+    /// Times are compared by their tick counts at the first event's frame
+    /// rate, so events at different frame rates are measured by when they
+    /// start and end.
+    pub fn duration(&self) -> Timecode {
+        let Some(fps) = self.events.first().map(|event| event.time_in.frame_rate()) else {
+            return Timecode::default();
+        };
+
+        let time_in = self.events.iter().map(|event| event.time_in).min_by_key(|time_in| time_in.ticks_at(fps));
+        let time_out = self.events.iter().map(|event| event.time_out).max_by_key(|time_out| time_out.ticks_at(fps));
+
+        match (time_in, time_out) {
+            (Some(time_in), Some(time_out)) => time_out - time_in,
+            _ => Timecode::default(),
+        }
+    }
+
+    /// Stably sorts the track's events by `time_in`, then by `channel`
+    ///
+    /// Start times are compared as tick counts at the frame rate of the
+    /// first event, so events at other frame rates sort by when they start
+    /// rather than by their timecode groups.
+    pub fn sort_events(&mut self) {
+        let Some(fps) = self.events.first().map(|event| event.time_in.frame_rate()) else {
+            return;
+        };
+
+        self.events.sort_by_key(|event| (event.time_in.ticks_at(fps), event.channel));
+    }
+
+    /// The distinct channel numbers used by the track's events, in
+    /// ascending order
+    ///
+    /// A multichannel clip has one event row per channel, so a stereo clip
+    /// contributes channels 1 and 2.
+    pub fn channels(&self) -> Vec<u32> {
+        let mut channels = self.events.iter().map(|event| event.channel).collect::<Vec<_>>();
+        channels.sort_unstable();
+        channels.dedup();
+        channels
+    }
+}
+
+impl IntoIterator for EDLTrack {
+    type Item = EDLTrackEvent;
+    type IntoIter = std::vec::IntoIter<EDLTrackEvent>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.events.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a EDLTrack {
+    type Item = &'a EDLTrackEvent;
+    type IntoIter = std::slice::Iter<'a, EDLTrackEvent>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.events.iter()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLTrackDelay` Implementation --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// A track's `USER DELAY`, e.g. `512 Samples`
+///
+/// The amount may be negative, and a delay written without a unit is read
+/// as samples.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EDLTrackDelay {
+    pub amount: i64,
+    pub unit: EDLUnit,
+}
+
+impl EDLTrackDelay {
+    /// Formats the delay as it is written in the `USER DELAY` field
+    pub fn to_edl_string(&self) -> String {
+        format!("{} {}", self.amount, self.unit.as_str())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLTrackState` Implementation --
+//
+///////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EDLTrackState {
+    pub inactive: bool,
+    pub muted: bool,
+    pub solo: bool,
+    pub hidden: bool,
+    /// State tokens that were not recognised, in the order they appeared
+    pub unknown: Vec<String>,
+}
+
+impl EDLTrackState {
+    /// Parses a whitespace separated list of track states, e.g.
+    /// `"Inactive Muted"`
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(state_string: &str) -> Self {
+        let mut state = Self::default();
+
+        for token in state_string.split_whitespace() {
+            match token {
+                "Inactive" => state.inactive = true,
+                "Muted" => state.muted = true,
+                "Solo" => state.solo = true,
+                "Hidden" => state.hidden = true,
+                _ => state.unknown.push(token.to_string()),
+            }
+        }
+
+        state
+    }
+
+    /// Formats the state as the whitespace separated list that
+    /// `EDLTrackState::from_str` accepts
+    pub fn to_edl_string(&self) -> String {
+        let flags = [
+            (self.inactive, "Inactive"),
+            (self.muted, "Muted"),
+            (self.solo, "Solo"),
+            (self.hidden, "Hidden"),
+        ];
+
+        flags
+            .iter()
+            .filter(|(is_set, _)| *is_set)
+            .map(|(_, token)| *token)
+            .chain(self.unknown.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////
@@ -137,19 +486,72 @@ impl EDLTrack {
 //
 ///////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Default, PartialEq, PartialOrd, Ord, Clone, Eq)]
+#[derive(Debug, Default, PartialEq, PartialOrd, Ord, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EDLTrackEvent {
     pub channel: u32,
     pub event: u32,
     pub name: String,
     pub time_in: Timecode,
     pub time_out: Timecode,
+    /// The event's DURATION column as written in the EDL, which should equal
+    /// `time_out - time_in`; see `EDLTrackEvent::has_consistent_duration`
+    pub duration: Timecode,
     pub timestamp: Timecode,
-    pub state: bool,
+    /// `true` when the event's STATE column reads `Muted`
+    pub is_muted: bool,
     flags: u8,
 }
 
 impl EDLTrackEvent {
+    /// Constructs an unmuted event without a timestamp, whose `duration` is
+    /// `time_out - time_in`
+    pub fn new(channel: u32, event: u32, name: &str, time_in: Timecode, time_out: Timecode) -> Self {
+        Self {
+            channel,
+            event,
+            name: name.to_string(),
+            time_in,
+            time_out,
+            duration: time_out - time_in,
+            timestamp: Timecode::with_fps(time_in.frame_rate()),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the event's TIMESTAMP column, and `EDLEVENT_FLAG_HAS_TIMESTAMP`
+    /// so that it is written out
+    pub fn with_timestamp(mut self, timestamp: Timecode) -> Self {
+        self.timestamp = timestamp;
+        self.set_flag(EDLEVENT_FLAG_HAS_TIMESTAMP);
+        self
+    }
+
+    pub fn with_muted(mut self, is_muted: bool) -> Self {
+        self.is_muted = is_muted;
+        self
+    }
+
+    /// Moves the event later by `offset`, adjusting `time_in`, `time_out`
+    /// and `timestamp` together
+    pub fn shift(&mut self, offset: Timecode) {
+        self.time_in += offset;
+        self.time_out += offset;
+        self.timestamp += offset;
+    }
+
+    /// Moves the event by a signed number of frames, adjusting `time_in`,
+    /// `time_out` and `timestamp` together and saturating each at zero
+    pub fn shift_signed(&mut self, frames: i64) {
+        let shift_timecode = |timecode: Timecode| {
+            if frames < 0 { timecode.sub_frames(frames.unsigned_abs()) } else { timecode.add_frames(frames as u64) }
+        };
+
+        self.time_in = shift_timecode(self.time_in);
+        self.time_out = shift_timecode(self.time_out);
+        self.timestamp = shift_timecode(self.timestamp);
+    }
+
     // TODO: de-duplicate this code
     pub fn check_flag(&self, flag: u8) -> bool {
         self.flags & flag == flag
@@ -164,54 +566,169 @@ impl EDLTrackEvent {
     }
 }
 
+impl EDLTrackEvent {
+    /// Returns `true` if `duration` matches the span from `time_in` to
+    /// `time_out`, counted in frames
+    pub fn has_consistent_duration(&self) -> bool {
+        (self.time_out - self.time_in).to_frames() == self.duration.to_frames()
+    }
+
+    /// The event's `(time_in, time_out)`
+    pub fn time_range(&self) -> (Timecode, Timecode) {
+        (self.time_in, self.time_out)
+    }
+
+    /// Returns `true` if `timecode` falls within the half-open range
+    /// `[time_in, time_out)`
+    ///
+    /// `timecode` may be at a different frame rate to the event, since the
+    /// comparison is made in ticks at the event's frame rate.
+    pub fn contains(&self, timecode: Timecode) -> bool {
+        let fps = self.time_in.frame_rate();
+        let ticks = timecode.ticks_at(fps);
+        self.time_in.to_ticks() <= ticks && ticks < self.time_out.ticks_at(fps)
+    }
+
+    /// Returns `true` if both events are on the same channel and their
+    /// non-empty `[time_in, time_out)` ranges intersect
+    ///
+    /// Times are compared as tick counts at this event's frame rate, so the
+    /// events may be at different frame rates.
+    pub fn overlaps(&self, other: &EDLTrackEvent) -> bool {
+        let fps = self.time_in.frame_rate();
+        let (time_in, time_out) = (self.time_in.ticks_at(fps), self.time_out.ticks_at(fps));
+        let (other_time_in, other_time_out) = (other.time_in.ticks_at(fps), other.time_out.ticks_at(fps));
+
+        self.channel == other.channel
+        && time_in < time_out
+        && other_time_in < other_time_out
+        && time_in < other_time_out
+        && other_time_in < time_out
+    }
+}
+
+/// The event's `[time_in, time_out)`, made independent of its frame rate
+/// with `TimeRange::from_timecodes`
+impl From<&EDLTrackEvent> for TimeRange {
+    fn from(event: &EDLTrackEvent) -> Self {
+        TimeRange::from_timecodes(event.time_in, event.time_out)
+    }
+}
+
+impl EDLTrackEvent {
+    /// Maps each event column to its position in `header_row`, or returns
+    /// the first required column that the header row is missing
+    fn column_indices(header_row: &str) -> Result<[Option<usize>; EDLTRACKEVENTCOLUMN_SIZE], EDLTrackEventColumn> {
+        let mut column_indices = [None; EDLTRACKEVENTCOLUMN_SIZE];
+        for (i, column_name) in header_row.split('\t').enumerate() {
+            if let Some(column) = EDLTrackEventColumn::from_column_name(column_name) {
+                column_indices[column.as_usize()] = Some(i);
+            }
+        }
+
+        match EDLTrackEventColumn::all_variants().iter().find(|column| !column.is_optional() && column_indices[column.as_usize()].is_none()) {
+            Some(&missing_column) => Err(missing_column),
+            None => Ok(column_indices),
+        }
+    }
+}
+
+/// Reads a track's event table, whose columns are `CHANNEL`, `EVENT`,
+/// `CLIP NAME`, `START TIME`, `END TIME`, `DURATION`, `TIMESTAMP` and
+/// `STATE`, in the order given by the header row
+///
+/// Timecodes are read at the given frame rate.
 impl ParseTable<Self, FrameRate> for EDLTrackEvent {
     const TABLE_TOTAL_COLUMNS: usize = 8;
-    fn parse_table(table_data: &[String], default_frame_rate: FrameRate) -> Option<Vec<Self>> {
+
+    /// Event tables leave out the CHANNEL column for mono tracks and the
+    /// TIMESTAMP column in some exports, so rows are read against the
+    /// columns named in the header row
+    fn expected_columns(header_row: &str) -> usize {
+        header_row.split('\t').count()
+    }
+
+    /// Rows may be wider than the header row when a clip name contains
+    /// tabs, in which case the overflow columns are part of the clip name
+    fn is_table_row(header_row: &str, line: &str) -> bool {
+        let header_columns = Self::expected_columns(header_row);
+        EDL_TRACK_EVENT_VALID_COLUMN_WIDTHS.contains(&header_columns)
+        && line.split('\t').count() >= header_columns
+    }
+
+    fn parse_table<S: AsRef<str>>(table_data: &[S], default_frame_rate: FrameRate) -> Result<Vec<Self>, EDLError> {
         let mut edl_events = Vec::<Self>::with_capacity(table_data.len());
-        let mut contains_timestamp = false;
+        let Some(header_row) = table_data.first() else {
+            return Ok(edl_events);
+        };
 
-        for (i, line) in table_data.iter().enumerate() {
-            let parts = line.split("\t").into_iter().collect::<Vec<_>>();
+        let header_row = header_row.as_ref();
+        let column_indices = EDLTrackEvent::column_indices(header_row).map_err(|missing_column| EDLError::MissingColumn {
+            line: 1,
+            column: missing_column.column_name().to_string(),
+        })?;
 
-            if (parts.len() == Self::TABLE_TOTAL_COLUMNS || parts.len() == Self::TABLE_TOTAL_COLUMNS - 1) && i > 0 {
-                let state =
-                    if parts[parts.len() - 1].trim() == "Muted" {
-                        true
-                    } else {
-                        false
-                    };
-
-                let timestamp =
-                    if contains_timestamp {
-                        Timecode::from_str(parts[parts.len() - 2].trim(), default_frame_rate).expect("EDLTrackEvent time in column should be a valid timecode string")
-                    } else {
-                        Timecode::with_fps(default_frame_rate)
-                    };
-
-                let edl_event = Self {
-                    channel: parts[0].trim().parse::<u32>().expect("EDLTrackEvent channel column should be a valid number"),
-                    event: parts[1].trim().parse::<u32>().expect("EDLTrackEvent event column should be a valid number"),
-                    name: parts[2].trim().to_string(),
-                    time_in: Timecode::from_str(parts[3].trim(), default_frame_rate).expect("EDLTrackEvent time in column should be a valid timecode string"),
-                    time_out: Timecode::from_str(parts[4].trim(), default_frame_rate).expect("EDLTrackEvent time in column should be a valid timecode string"),
+        let header_columns = header_row.split('\t').count();
+        let has_valid_width = EDL_TRACK_EVENT_VALID_COLUMN_WIDTHS.contains(&header_columns);
+
+        for (i, line) in table_data.iter().enumerate().skip(1) {
+            let parts = line.as_ref().split('\t').collect::<Vec<_>>();
+            let line_number = i + 1;
+
+            // Matches `is_table_row`, without re-reading the header row
+            if has_valid_width && parts.len() >= header_columns {
+                use EDLTrackEventColumn::*;
+
+                // A clip name containing tabs spills into the columns after
+                // it, so the columns from the clip name onwards are shifted
+                // right by the number of extra columns in the row
+                let overflow_columns = parts.len() - header_columns;
+                let clip_name_index = column_indices[ClipName.as_usize()].expect("required columns are checked against the header row");
+                let row_index = |index: usize| if index > clip_name_index { index + overflow_columns } else { index };
+
+                let column = |column: EDLTrackEventColumn| column_indices[column.as_usize()].map(|index| (parts[row_index(index)], row_index(index) + 1));
+                let required_column = |column_name: EDLTrackEventColumn| column(column_name).expect("required columns are checked against the header row");
+
+                let channel = match column(Channel) {
+                    Some((channel, column_number)) => parse_table_number(channel, line_number, column_number)?,
+                    None => 1,
+                };
+
+                let timestamp = match column(Timestamp) {
+                    Some((timestamp, column_number)) => parse_table_timecode(timestamp, default_frame_rate, line_number, column_number)?,
+                    None => Timecode::with_fps(default_frame_rate),
+                };
+
+                let (event, event_column) = required_column(Event);
+                let name = parts[clip_name_index..=clip_name_index + overflow_columns].join("\t");
+                let (time_in, time_in_column) = required_column(StartTime);
+                let (time_out, time_out_column) = required_column(EndTime);
+                let (duration, duration_column) = required_column(Duration);
+                let (state, _) = required_column(State);
+
+                let mut edl_event = Self {
+                    channel,
+                    event: parse_table_number(event, line_number, event_column)?,
+                    name: name.trim().to_string(),
+                    time_in: parse_table_timecode(time_in, default_frame_rate, line_number, time_in_column)?,
+                    time_out: parse_table_timecode(time_out, default_frame_rate, line_number, time_out_column)?,
+                    duration: parse_table_timecode(duration, default_frame_rate, line_number, duration_column)?,
                     timestamp,
-                    state,
+                    is_muted: state.trim() == "Muted",
                     ..Self::default()
                 };
 
-                edl_events.push(edl_event);
-            }
+                if column(Timestamp).is_some() {
+                    edl_event.set_flag(EDLEVENT_FLAG_HAS_TIMESTAMP);
+                }
 
-            else if (parts.len() == Self::TABLE_TOTAL_COLUMNS || parts.len() == Self::TABLE_TOTAL_COLUMNS - 1) && i == 0 {
-                contains_timestamp = parts[parts.len() - 2].trim() == "TIMESTAMP";
+                edl_events.push(edl_event);
             }
 
-            else { /* TODO: Report? */ }
+            else { /* Skipped rows are reported by `EDLParser` as `EDLWarning::SkippedRow` */ }
         }
-        
-        if edl_events.len() > 0 { return Some(edl_events); }
 
-        None
+        Ok(edl_events)
     }
 }
 
@@ -221,42 +738,162 @@ impl ParseTable<Self, FrameRate> for EDLTrackEvent {
 //
 ///////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Default, PartialEq, PartialOrd, Ord, Clone, Eq)]
+#[derive(Debug, Default, PartialEq, PartialOrd, Ord, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EDLMarker {
     pub id: u32,
-    pub location: Timecode,
+    pub location: EDLMarkerLocation,
     pub time_reference: u32,
     pub unit: EDLUnit,
     pub name: String,
     pub comment: String,
+    pub source_line: EDLSourceLine,
 }
 
+/// Reads the markers table, whose columns are `#`, `LOCATION`,
+/// `TIME REFERENCE`, `UNITS`, `NAME` and `COMMENTS`
+///
+/// Timecode locations are read at the given frame rate.
 impl ParseTable<Self, FrameRate> for EDLMarker {
     const TABLE_TOTAL_COLUMNS: usize = 6;
-    fn parse_table(table_data: &[String], default_frame_rate: FrameRate) -> Option<Vec<Self>> {
+    fn parse_table<S: AsRef<str>>(table_data: &[S], default_frame_rate: FrameRate) -> Result<Vec<Self>, EDLError> {
         let mut edl_markers = Vec::<Self>::with_capacity(table_data.len());
 
         for (i, line) in table_data.iter().enumerate() {
-            let parts = line.split("\t").into_iter().collect::<Vec<_>>();
+            let parts = line.as_ref().split('\t').collect::<Vec<_>>();
+            let line_number = i + 1;
+
             if parts.len() == Self::TABLE_TOTAL_COLUMNS && i > 0 {
+                let unit = EDLUnit::parse_field(parts[3]).ok_or_else(|| EDLError::UnknownUnit {
+                    line: line_number,
+                    column: 4,
+                    value: parts[3].trim().to_string(),
+                })?;
+
+                let location = EDLMarkerLocation::from_str(parts[1].trim(), &unit, default_frame_rate)
+                    .ok_or_else(|| EDLError::InvalidMarkerLocation {
+                        line: line_number,
+                        column: 2,
+                        value: parts[1].trim().to_string(),
+                    })?;
+
                 edl_markers.push(
                     Self {
-                        id: parts[0].trim().parse::<u32>().expect("EDLMarker id column should be a valid number"),
-                        location: Timecode::from_str(parts[1].trim(), default_frame_rate).expect("EDLMarker location column should be a valid timecode string"),
-                        time_reference: parts[2].trim().parse::<u32>().expect("EDLMarker time reference column should be a valid number"),
-                        unit: EDLUnit::from_str(parts[3].trim()).expect("EDLMarker unit column should be valid unit option"),
+                        id: parse_table_number(parts[0], line_number, 1)?,
+                        location,
+                        time_reference: parse_table_number(parts[2], line_number, 3)?,
+                        unit,
                         name: parts[4].trim().to_string(),
                         comment: parts[5].trim().to_string(),
+                        ..Self::default()
                     }
                 );
             }
 
-            else { /* TODO: Report? */ }
+            else { /* Skipped rows are reported by `EDLParser` as `EDLWarning::SkippedRow` */ }
         }
-        
-        if edl_markers.len() > 0 { return Some(edl_markers); }
 
-        None
+        Ok(edl_markers)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLMarkerLocation` Implementation --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// The location of a marker, in the unit given by the marker's UNITS column
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EDLMarkerLocation {
+    BarsBeats { bars: u32, beats: u32, ticks: u32 },
+    FeetFrames { feet: u32, frames: u32 },
+    MinutesSeconds { minutes: u32, milliseconds: u32 },
+    Samples(u64),
+    Timecode(Timecode),
+}
+
+impl Default for EDLMarkerLocation {
+    fn default() -> Self {
+        EDLMarkerLocation::Timecode(Timecode::default())
+    }
+}
+
+impl EDLMarkerLocation {
+    /// Parses a marker location according to `unit`
+    ///
+    /// Protools writes the location of `Samples` markers as a timecode,
+    /// with the sample count in the TIME REFERENCE column, so a `Samples`
+    /// location that is not a plain sample count is parsed as a timecode.
+    ///
+    /// Accepted forms:
+    ///
+    /// Bars|Beats   -> `5|2|480`
+    /// Feet+Frames  -> `12+08`
+    /// Min:Sec      -> `1:05.250`
+    /// Samples      -> `288000` or `01:00:06:00`
+    /// Timecode     -> `01:00:06:00`
+    ///
+    pub fn from_str(location_string: &str, unit: &EDLUnit, fps: FrameRate) -> Option<Self> {
+        let location_string = location_string.trim();
+        let parse_number = |value: &str| value.trim().parse::<u32>().ok();
+
+        match unit {
+            EDLUnit::BarsBeats => {
+                let mut parts = location_string.split('|');
+                let bars = parse_number(parts.next()?)?;
+                let beats = parse_number(parts.next()?)?;
+                let ticks = parts.next().map_or(Some(0), parse_number)?;
+                if parts.next().is_some() { return None; }
+                Some(EDLMarkerLocation::BarsBeats { bars, beats, ticks })
+            },
+
+            EDLUnit::FeetFrames => {
+                let (feet, frames) = location_string.split_once('+')?;
+                let frames = frames.split('.').next()?;
+                Some(EDLMarkerLocation::FeetFrames { feet: parse_number(feet)?, frames: parse_number(frames)? })
+            },
+
+            EDLUnit::MinutesSeconds => {
+                let (minutes, seconds) = location_string.split_once(':')?;
+                let (seconds, fraction) = seconds.split_once('.').unwrap_or((seconds, "0"));
+                let fraction = format!("{:0<3}", fraction.trim());
+                if fraction.len() != 3 { return None; }
+                Some(EDLMarkerLocation::MinutesSeconds {
+                    minutes: parse_number(minutes)?,
+                    milliseconds: parse_number(seconds)?.checked_mul(1000)?.checked_add(parse_number(&fraction)?)?,
+                })
+            },
+
+            EDLUnit::Samples => {
+                location_string.parse::<u64>().ok()
+                    .map(EDLMarkerLocation::Samples)
+                    .or_else(|| Timecode::from_str(location_string, fps).ok().map(EDLMarkerLocation::Timecode))
+            },
+
+            EDLUnit::Timecode => Timecode::from_str(location_string, fps).ok().map(EDLMarkerLocation::Timecode),
+        }
+    }
+
+    /// Formats the location in the form that `EDLMarkerLocation::from_str`
+    /// accepts for its unit
+    pub fn to_edl_string(&self) -> String {
+        match self {
+            EDLMarkerLocation::BarsBeats { bars, beats, ticks } => format!("{}|{}|{:03}", bars, beats, ticks),
+            EDLMarkerLocation::FeetFrames { feet, frames } => format!("{}+{:02}", feet, frames),
+            EDLMarkerLocation::MinutesSeconds { minutes, milliseconds } => format!("{}:{:02}.{:03}", minutes, milliseconds / 1000, milliseconds % 1000),
+            EDLMarkerLocation::Samples(samples) => samples.to_string(),
+            EDLMarkerLocation::Timecode(timecode) => timecode.to_string(),
+        }
+    }
+
+    /// Returns the location as a `Timecode`, if it is expressed as one
+    pub fn as_timecode(&self) -> Option<&Timecode> {
+        match self {
+            EDLMarkerLocation::Timecode(timecode) => Some(timecode),
+            _ => None,
+        }
     }
 }
 
@@ -266,7 +903,8 @@ impl ParseTable<Self, FrameRate> for EDLMarker {
 //
 ///////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EDLUnit {
     // TODO: Figure out what other units are acceptable
     // in Protools EDL
@@ -279,6 +917,7 @@ pub enum EDLUnit {
 }
 
 impl EDLUnit {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(unit_string: &str) -> Option<Self> {
         match unit_string.trim() {
             "Bars|Beats" => Some(EDLUnit::BarsBeats),
@@ -289,6 +928,29 @@ impl EDLUnit {
             _ => None,
         }
     }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EDLUnit::BarsBeats => "Bars|Beats",
+            EDLUnit::FeetFrames => "Feet+Frames",
+            EDLUnit::MinutesSeconds => "Min:Sec",
+            EDLUnit::Samples => "Samples",
+            EDLUnit::Timecode => "Timecode",
+        }
+    }
+}
+
+impl ParseField<Self> for EDLUnit {
+    fn parse_field(field_string: &str) -> Option<Self> {
+        EDLUnit::from_str(field_string)
+    }
+}
+
+impl TryFrom<&str> for EDLUnit {
+    type Error = FormatParseError;
+    fn try_from(unit_string: &str) -> Result<Self, Self::Error> {
+        EDLUnit::from_str(unit_string).ok_or_else(|| FormatParseError::UnknownUnit(unit_string.trim().to_string()))
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////
@@ -297,42 +959,53 @@ impl EDLUnit {
 //
 ///////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EDLPlugin {
     pub manufacturer: String,
     pub name: String,
     pub version: String,
     pub format: EDLPluginFormat,
     pub stems: String,
-    pub total_instances: String,
+    pub instances: EDLPluginInstance,
 }
 
+/// Reads the plug-ins table, whose columns are `MANUFACTURER`,
+/// `PLUG-IN NAME`, `VERSION`, `FORMAT`, `STEMS` and `NUMBER OF INSTANCES`
 impl ParseTable<Self, ()> for EDLPlugin {
     const TABLE_TOTAL_COLUMNS: usize = 6;
-    fn parse_table(table_data: &[String], _: ()) -> Option<Vec<Self>> {
+    fn parse_table<S: AsRef<str>>(table_data: &[S], _: ()) -> Result<Vec<Self>, EDLError> {
         let mut edl_plugins = Vec::<Self>::with_capacity(table_data.len());
 
         for (i, line) in table_data.iter().enumerate() {
-            let parts = line.split("\t").into_iter().collect::<Vec<_>>();
+            let parts = line.as_ref().split('\t').collect::<Vec<_>>();
+            let line_number = i + 1;
+
             if parts.len() == Self::TABLE_TOTAL_COLUMNS && i > 0 {
                 edl_plugins.push(
                     EDLPlugin {
                         manufacturer: parts[0].trim().to_string(),
                         name: parts[1].trim().to_string(),
                         version: parts[2].trim().to_string(),
-                        format: EDLPluginFormat::from_str(parts[3].trim()).expect("EDLPluginFormat should have a valid plugin format option"),
+                        format: EDLPluginFormat::from_str(parts[3].trim()).ok_or_else(|| EDLError::UnknownPluginFormat {
+                            line: line_number,
+                            column: 4,
+                            value: parts[3].trim().to_string(),
+                        })?,
                         stems: parts[4].trim().to_string(),
-                        ..EDLPlugin::default()
+                        instances: EDLPluginInstance::from_str(parts[5]).ok_or_else(|| EDLError::InvalidNumber {
+                            line: line_number,
+                            column: 6,
+                            value: parts[5].trim().to_string(),
+                        })?,
                     }
                 );
             }
 
-            else { /* TODO: Report? */ }
+            else { /* Skipped rows are reported by `EDLParser` as `EDLWarning::SkippedRow` */ }
         }
-        
-        if edl_plugins.len() > 0 { return Some(edl_plugins); }
 
-        None
+        Ok(edl_plugins)
     }
 }
 
@@ -342,20 +1015,48 @@ impl ParseTable<Self, ()> for EDLPlugin {
 //
 ///////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone)]
+/// The format of a plug-in, as given by the FORMAT column of the plug-ins
+/// listing
+///
+/// RTAS, TDM and AudioSuite plug-ins appear in sessions from older versions
+/// of Protools. Formats that are not recognised are kept as written in
+/// `Other`.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EDLPluginFormat {
-    // TODO: Figure out all possible formats
     #[default]
     AAXNative,
     AAXDSP,
+    AudioSuite,
+    RTAS,
+    TDM,
+    Other(String),
 }
 
 impl EDLPluginFormat {
+    /// Parses a plug-in format, returning `None` only for an empty string
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(format_string: &str) -> Option<Self> {
         match format_string.trim() {
+            "" => None,
             "AAX Native" => Some(EDLPluginFormat::AAXNative),
             "AAX DSP" => Some(EDLPluginFormat::AAXDSP),
-            _ => None,
+            "AudioSuite" => Some(EDLPluginFormat::AudioSuite),
+            "RTAS" => Some(EDLPluginFormat::RTAS),
+            "TDM" => Some(EDLPluginFormat::TDM),
+            other => Some(EDLPluginFormat::Other(other.to_string())),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            EDLPluginFormat::AAXNative => "AAX Native",
+            EDLPluginFormat::AAXDSP => "AAX DSP",
+            EDLPluginFormat::AudioSuite => "AudioSuite",
+            EDLPluginFormat::RTAS => "RTAS",
+            EDLPluginFormat::TDM => "TDM",
+            EDLPluginFormat::Other(format_string) => format_string,
         }
     }
 }
@@ -366,7 +1067,561 @@ impl EDLPluginFormat {
 //
 ///////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone)]
+/// The instance counts from a plugin's NUMBER OF INSTANCES column
+///
+/// Protools writes a bare count, e.g. `3`, which is read as that many
+/// instances that are all active. A count of the form `active/total`,
+/// e.g. `2/3`, carries the active instances separately.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EDLPluginInstance {
-    pub total_active: u32,
+    pub active: u32,
+    pub total: u32,
+}
+
+impl EDLPluginInstance {
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(instances_string: &str) -> Option<Self> {
+        let parse_number = |value: &str| value.trim().parse::<u32>().ok();
+
+        match instances_string.split_once('/') {
+            Some((active, total)) => {
+                let (active, total) = (parse_number(active)?, parse_number(total)?);
+                if active > total { return None; }
+                Some(Self { active, total })
+            },
+
+            None => {
+                let total = parse_number(instances_string)?;
+                Some(Self { active: total, total })
+            },
+        }
+    }
+
+    /// Number of instances that are not active
+    pub fn inactive(&self) -> u32 {
+        self.total - self.active
+    }
+}
+
+impl std::fmt::Display for EDLPluginInstance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.active == self.total {
+            write!(f, "{}", self.total)
+        } else {
+            write!(f, "{}/{}", self.active, self.total)
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLTrackEvent` Flags --
+//
+///////////////////////////////////////////////////////////////////////////
+
+pub const EDLEVENT_FLAG_DEFAULT: u8 = 0;
+/// Set on events parsed from a table with a TIMESTAMP column
+pub const EDLEVENT_FLAG_HAS_TIMESTAMP: u8 = 1 << 0;
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `ParseTable` Helpers --
+//
+///////////////////////////////////////////////////////////////////////////
+
+fn parse_table_number(value: &str, line: usize, column: usize) -> Result<u32, EDLError> {
+    value.trim().parse::<u32>().map_err(|_| EDLError::InvalidNumber {
+        line,
+        column,
+        value: value.trim().to_string(),
+    })
+}
+
+fn parse_table_timecode(value: &str, fps: FrameRate, line: usize, column: usize) -> Result<Timecode, EDLError> {
+    Timecode::from_str(value.trim(), fps).map_err(|source| EDLError::InvalidTimecode {
+        line,
+        column,
+        value: value.trim().to_string(),
+        source,
+    })
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLSession` Types Unit Tests --
+//
+///////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timecode(tc_string: &str) -> Timecode {
+        Timecode::from_str(tc_string, FrameRate::Fps25).expect("test timecode should be valid")
+    }
+
+    fn ntsc_timecode(tc_string: &str) -> Timecode {
+        Timecode::from_str(tc_string, FrameRate::Fps30(false)).expect("test timecode should be valid")
+    }
+
+    fn event(time_in: &str, time_out: &str) -> EDLTrackEvent {
+        EDLTrackEvent {
+            channel: 1,
+            event: 1,
+            name: "Clip".to_string(),
+            time_in: timecode(time_in),
+            time_out: timecode(time_out),
+            duration: timecode(time_out) - timecode(time_in),
+            timestamp: timecode(time_in),
+            ..EDLTrackEvent::default()
+        }
+    }
+
+    #[test]
+    fn track_event_time_range_contains() {
+        let edl_event = event("01:00:00:00", "01:00:05:00");
+        assert_eq!(edl_event.time_range(), (timecode("01:00:00:00"), timecode("01:00:05:00")));
+
+        assert!(edl_event.contains(timecode("01:00:00:00")));
+        assert!(edl_event.contains(timecode("01:00:04:24")));
+        assert!(!edl_event.contains(timecode("00:59:59:24")));
+        assert!(!edl_event.contains(timecode("01:00:05:00")));
+
+        let at_50fps = |tc_string: &str| Timecode::from_str(tc_string, FrameRate::Fps50).expect("test timecode should be valid");
+        assert!(edl_event.contains(at_50fps("01:00:00:00")));
+        assert!(edl_event.contains(at_50fps("01:00:04:49")));
+        assert!(!edl_event.contains(at_50fps("00:59:59:49")));
+        assert!(!edl_event.contains(at_50fps("01:00:05:00")));
+
+        assert!(!event("01:00:00:00", "01:00:00:00").contains(timecode("01:00:00:00")));
+    }
+
+    #[test]
+    fn track_event_shift() {
+        let mut edl_event = event("01:00:00:00", "01:00:05:00");
+        edl_event.shift(timecode("00:00:01:10"));
+
+        assert_eq!(edl_event.time_in, timecode("01:00:01:10"));
+        assert_eq!(edl_event.time_out, timecode("01:00:06:10"));
+        assert_eq!(edl_event.timestamp, timecode("01:00:01:10"));
+    }
+
+    #[test]
+    fn track_event_shift_signed() {
+        let mut edl_event = event("00:00:01:00", "00:00:02:00");
+        edl_event.shift_signed(-10);
+
+        assert_eq!(edl_event.time_in, timecode("00:00:00:15"));
+        assert_eq!(edl_event.time_out, timecode("00:00:01:15"));
+        assert_eq!(edl_event.timestamp, timecode("00:00:00:15"));
+
+        edl_event.shift_signed(-30);
+        assert_eq!(edl_event.time_in, timecode("00:00:00:00"));
+        assert_eq!(edl_event.time_out, timecode("00:00:00:10"));
+    }
+
+    #[test]
+    fn track_event_muted_state() {
+        let table = [
+            "CHANNEL\tEVENT\tCLIP NAME\tSTART TIME\tEND TIME\tDURATION\tSTATE".to_string(),
+            "1\t1\tClip A\t01:00:00:00\t01:00:01:00\t00:00:01:00\tMuted".to_string(),
+            "1\t2\tClip B\t01:00:02:00\t01:00:03:00\t00:00:01:00\tUnmuted".to_string(),
+        ];
+
+        let events = EDLTrackEvent::parse_table(&table, FrameRate::Fps25).expect("event table should parse");
+        assert!(!events[0].check_flag(EDLEVENT_FLAG_HAS_TIMESTAMP));
+        assert!(events[0].is_muted);
+        assert!(!events[1].is_muted);
+    }
+
+    #[test]
+    fn track_event_duration_column() {
+        let table = [
+            "CHANNEL\tEVENT\tCLIP NAME\tSTART TIME\tEND TIME\tDURATION\tTIMESTAMP\tSTATE".to_string(),
+            "1\t1\tClip A\t01:00:00:00\t01:00:01:10\t00:00:01:10\t01:00:00:00\tUnmuted".to_string(),
+            "1\t2\tClip B\t01:00:02:00\t01:00:03:00\t00:00:01:01\t01:00:02:00\tUnmuted".to_string(),
+        ];
+
+        let events = EDLTrackEvent::parse_table(&table, FrameRate::Fps25).expect("event table should parse");
+        assert!(events[0].check_flag(EDLEVENT_FLAG_HAS_TIMESTAMP));
+        assert_eq!(events[0].duration, timecode("00:00:01:10"));
+        assert_eq!(events[0].timestamp, timecode("01:00:00:00"));
+        assert!(events[0].has_consistent_duration());
+        assert!(!events[1].has_consistent_duration());
+    }
+
+    #[test]
+    fn plugin_total_instances() {
+        let table = [
+            "MANUFACTURER\tPLUG-IN NAME\tVERSION\tFORMAT\tSTEMS\tNUMBER OF INSTANCES".to_string(),
+            "Avid    \tChannel Strip    \t22.6.0    \tAAX Native    \tMono / Mono    \t12    ".to_string(),
+        ];
+
+        let plugins = EDLPlugin::parse_table(&table, ()).expect("plugin table should parse");
+        assert_eq!(plugins[0].name, "Channel Strip");
+        assert_eq!(plugins[0].instances, EDLPluginInstance { active: 12, total: 12 });
+    }
+
+    #[test]
+    fn plugin_formats() {
+        let table = [
+            "MANUFACTURER\tPLUG-IN NAME\tVERSION\tFORMAT\tSTEMS\tNUMBER OF INSTANCES".to_string(),
+            "Avid    \tEQ3 7-Band    \t22.6.0    \tAAX Native    \tMono / Mono    \t1    ".to_string(),
+            "Digidesign    \tD-Verb    \t6.4.1    \tRTAS    \tStereo / Stereo    \t1    ".to_string(),
+            "Digidesign    \tDigiRack EQ 3    \t7.3.1    \tTDM    \tMono / Mono    \t2    ".to_string(),
+            "Avid    \tTime Shift    \t22.6.0    \tAudioSuite    \tMono / Mono    \t1    ".to_string(),
+            "Acme    \tFuture Verb    \t1.0.0    \tCLAP    \tStereo / Stereo    \t1    ".to_string(),
+        ];
+
+        let formats = EDLPlugin::parse_table(&table, ())
+            .expect("plug-in table should parse")
+            .into_iter()
+            .map(|plugin| plugin.format)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            formats,
+            [
+                EDLPluginFormat::AAXNative,
+                EDLPluginFormat::RTAS,
+                EDLPluginFormat::TDM,
+                EDLPluginFormat::AudioSuite,
+                EDLPluginFormat::Other("CLAP".to_string()),
+            ]
+        );
+        assert_eq!(formats[4].as_str(), "CLAP");
+
+        let missing_format = [table[0].clone(), "Avid\tEQ3 7-Band\t22.6.0\t \tMono / Mono\t1".to_string()];
+        assert!(matches!(EDLPlugin::parse_table(&missing_format, ()), Err(EDLError::UnknownPluginFormat { line: 2, column: 4, .. })));
+    }
+
+    #[test]
+    fn plugin_instance_counts() {
+        let table = [
+            "MANUFACTURER\tPLUG-IN NAME\tVERSION\tFORMAT\tSTEMS\tNUMBER OF INSTANCES".to_string(),
+            "Avid    \tPro Limiter    \t22.6.0    \tAAX DSP    \tStereo / Stereo    \t2/5    ".to_string(),
+            "Avid    \tPro Limiter    \t22.6.0    \tAAX DSP    \tStereo / Stereo    \tmany    ".to_string(),
+        ];
+
+        let instances = EDLPluginInstance::from_str(" 2/5 ").expect("instance counts should parse");
+        assert_eq!(instances, EDLPluginInstance { active: 2, total: 5 });
+        assert_eq!(instances.inactive(), 3);
+        assert_eq!(instances.to_string(), "2/5");
+        assert_eq!(EDLPluginInstance::from_str("5/2"), None);
+
+        let result = EDLPlugin::parse_table(&table, ());
+        assert!(matches!(result, Err(EDLError::InvalidNumber { line: 3, column: 6, .. })));
+        assert_eq!(EDLPlugin::parse_table(&table[..2], ()).expect("plugin table should parse")[0].instances, instances);
+    }
+
+    #[test]
+    fn track_state_flags() {
+        let state = EDLTrackState::from_str("Inactive  Muted Hidden Frozen");
+        assert!(state.inactive);
+        assert!(state.muted);
+        assert!(!state.solo);
+        assert!(state.hidden);
+        assert_eq!(state.unknown, ["Frozen"]);
+    }
+
+    #[test]
+    fn marker_location_by_unit() {
+        let table = [
+            "#\tLOCATION\tTIME REFERENCE\tUNITS\tNAME\tCOMMENTS".to_string(),
+            "1\t2880000\t2880000\tSamples\tSample Marker\t".to_string(),
+            "2\t01:00:06:00\t288000\tSamples\tTimecode Marker\t".to_string(),
+            "3\t1:05.250\t3132000\tMin:Sec\tMinutes Marker\t".to_string(),
+            "4\t5|2|480\t0\tBars|Beats\tBars Marker\t".to_string(),
+            "5\t12+08\t0\tFeet+Frames\tFeet Marker\t".to_string(),
+        ];
+
+        let markers = EDLMarker::parse_table(&table, FrameRate::Fps25).expect("marker table should parse");
+        assert_eq!(markers[0].location, EDLMarkerLocation::Samples(2880000));
+        assert_eq!(markers[1].location, EDLMarkerLocation::Timecode(timecode("01:00:06:00")));
+        assert_eq!(markers[2].location, EDLMarkerLocation::MinutesSeconds { minutes: 1, milliseconds: 5250 });
+        assert_eq!(markers[3].location, EDLMarkerLocation::BarsBeats { bars: 5, beats: 2, ticks: 480 });
+        assert_eq!(markers[4].location, EDLMarkerLocation::FeetFrames { feet: 12, frames: 8 });
+    }
+
+    #[test]
+    fn unit_parse_field() {
+        fn parse<T: ParseField<T>>(field_string: &str) -> Option<T> {
+            T::parse_field(field_string)
+        }
+
+        for unit in [EDLUnit::BarsBeats, EDLUnit::FeetFrames, EDLUnit::MinutesSeconds, EDLUnit::Samples, EDLUnit::Timecode] {
+            assert_eq!(parse::<EDLUnit>(unit.as_str()), Some(unit));
+        }
+
+        assert_eq!(parse::<EDLUnit>(" Samples  "), Some(EDLUnit::Samples));
+        assert_eq!(parse::<EDLUnit>("Frames"), None);
+    }
+
+    #[test]
+    fn unit_try_from_str() {
+        assert_eq!(EDLUnit::try_from("Min:Sec"), Ok(EDLUnit::MinutesSeconds));
+        assert_eq!(EDLUnit::try_from("Frames"), Err(FormatParseError::UnknownUnit("Frames".to_string())));
+    }
+
+    #[test]
+    fn marker_table_strict() {
+        let table = [
+            "#\tLOCATION\tTIME REFERENCE\tUNITS\tNAME\tCOMMENTS".to_string(),
+            "1\t01:00:00:00\t0\tSamples\tFirst Act\t".to_string(),
+            "2\t01:00:06:00\t288000\tSamples\tSecond Act".to_string(),
+        ];
+
+        assert_eq!(EDLMarker::parse_table(&table, FrameRate::Fps25).expect("marker table should parse").len(), 1);
+        assert_eq!(
+            EDLMarker::parse_table_strict(&table, FrameRate::Fps25),
+            Err(EDLError::ColumnCountMismatch { line: 3, expected: 6, found: 5 })
+        );
+        assert_eq!(EDLMarker::parse_table_strict(&table[..2], FrameRate::Fps25).expect("well formed marker table should parse").len(), 1);
+    }
+
+    #[test]
+    fn marker_location_mismatched_unit() {
+        let table = [
+            "#\tLOCATION\tTIME REFERENCE\tUNITS\tNAME\tCOMMENTS".to_string(),
+            "1\t01:00:06:00\t0\tMin:Sec\tMarker\t".to_string(),
+        ];
+
+        let result = EDLMarker::parse_table(&table, FrameRate::Fps25);
+        assert!(matches!(result, Err(EDLError::InvalidMarkerLocation { line: 2, column: 2, .. })));
+    }
+
+    #[test]
+    fn media_file_and_clip_display() {
+        let media_file = EDLMediaFile {
+            file_name: "Dialog_01.wav".to_string(),
+            location: Some("Macintosh HD:Sessions:Demo:Audio Files:".to_string()),
+            ..EDLMediaFile::default()
+        };
+
+        let clip = EDLClip {
+            clip_name: "Dialog_01-01".to_string(),
+            source_file: "Dialog_01.wav".to_string(),
+            ..EDLClip::default()
+        };
+
+        assert_eq!(media_file.to_string(), "Dialog_01.wav (Macintosh HD:Sessions:Demo:Audio Files:)");
+        assert_eq!(EDLMediaFile { location: None, ..media_file }.to_string(), "Dialog_01.wav");
+        assert_eq!(clip.to_string(), "Dialog_01-01 <- Dialog_01.wav");
+    }
+
+    #[test]
+    fn media_file_path() {
+        let windows_file = EDLMediaFile {
+            file_name: "clip.wav".to_string(),
+            location: Some("D:\\Sessions\\Foo\\Audio Files\\clip.wav".to_string()),
+            ..EDLMediaFile::default()
+        };
+
+        let windows_path = windows_file.path();
+        assert!(windows_path.starts_with("D:"));
+        assert!(windows_path.ends_with(["Sessions", "Foo", "Audio Files", "clip.wav"].iter().collect::<PathBuf>()));
+        assert_eq!(windows_path.file_name().and_then(|file_name| file_name.to_str()), Some("clip.wav"));
+        assert_eq!(windows_file.file_stem(), Some("clip"));
+        assert_eq!(windows_file.extension(), Some("wav"));
+
+        let posix_file = EDLMediaFile {
+            file_name: "Dialog_01.wav".to_string(),
+            location: Some("/Volumes/Media/Sessions/Demo/Audio Files/".to_string()),
+            ..EDLMediaFile::default()
+        };
+
+        let expected_path = [std::path::MAIN_SEPARATOR_STR, "Volumes", "Media", "Sessions", "Demo", "Audio Files", "Dialog_01.wav"].iter().collect::<PathBuf>();
+        assert_eq!(posix_file.path(), expected_path);
+
+        let mac_file = EDLMediaFile {
+            location: Some("Macintosh HD:Sessions:Demo:Audio Files:".to_string()),
+            ..posix_file.clone()
+        };
+        assert_eq!(mac_file.path(), ["Macintosh HD", "Sessions", "Demo", "Audio Files", "Dialog_01.wav"].iter().collect::<PathBuf>());
+
+        let unlocated_file = EDLMediaFile { location: None, file_name: "Missing_01".to_string(), ..posix_file };
+        assert_eq!(unlocated_file.path(), PathBuf::from("Missing_01"));
+        assert_eq!(unlocated_file.extension(), None);
+    }
+
+    #[test]
+    fn track_duration() {
+        let mut track = EDLTrack::with_name("DIA");
+        assert_eq!(track.duration(), Timecode::default());
+
+        track.events.push(event("01:00:04:00", "01:00:06:00"));
+        track.events.push(event("01:00:01:00", "01:00:03:00"));
+        assert_eq!(track.duration(), timecode("00:00:05:00"));
+
+        // By their groups the 30fps event would start later and end later,
+        // but in time it starts earlier and ends earlier
+        let mut mixed_rates = EDLTrack::with_name("MX");
+        mixed_rates.events.push(event("01:00:04:20", "01:00:04:24"));
+        mixed_rates.events.push(EDLTrackEvent {
+            time_in: ntsc_timecode("01:00:04:22"),
+            time_out: ntsc_timecode("01:00:04:28"),
+            ..event("01:00:00:00", "01:00:00:00")
+        });
+        let duration = mixed_rates.duration();
+        assert_eq!(duration.seconds::<u32>(), 0);
+        assert_eq!(duration.frames::<u32>(), 5);
+        assert_eq!(duration.ticks::<u32>(), 67);
+    }
+
+    #[test]
+    fn track_event_constructor() {
+        let mut track = EDLTrack::with_name("FX");
+        track.events.push(EDLTrackEvent::new(1, 1, "Door_Slam", timecode("01:00:02:00"), timecode("01:00:03:10")));
+        track.events.push(
+            EDLTrackEvent::new(2, 1, "Door_Slam", timecode("01:00:02:00"), timecode("01:00:03:10"))
+                .with_timestamp(timecode("01:00:02:00"))
+                .with_muted(true)
+        );
+
+        assert_eq!(track.events[0].duration, timecode("00:00:01:10"));
+        assert!(track.events[0].has_consistent_duration());
+        assert!(!track.events[0].check_flag(EDLEVENT_FLAG_HAS_TIMESTAMP));
+        assert!(!track.events[0].is_muted);
+
+        assert_eq!(track.events[1].timestamp, timecode("01:00:02:00"));
+        assert!(track.events[1].check_flag(EDLEVENT_FLAG_HAS_TIMESTAMP));
+        assert!(track.events[1].is_muted);
+        assert_eq!(track.channels(), [1, 2]);
+    }
+
+    #[test]
+    fn track_sort_events() {
+        let mut track = EDLTrack::with_name("DIA");
+        track.sort_events();
+
+        track.events.push(EDLTrackEvent::new(2, 1, "C-right", timecode("01:00:06:00"), timecode("01:00:08:00")));
+        track.events.push(EDLTrackEvent::new(1, 1, "A", timecode("01:00:00:00"), timecode("01:00:02:00")));
+        track.events.push(EDLTrackEvent::new(1, 2, "C-left", timecode("01:00:06:00"), timecode("01:00:07:00")));
+        track.events.push(EDLTrackEvent::new(1, 3, "C-left-tail", timecode("01:00:06:00"), timecode("01:00:08:00")));
+
+        track.events.push(EDLTrackEvent::new(1, 4, "B-late", timecode("01:00:01:24"), timecode("01:00:03:00")));
+
+        // Frame 40 at 50fps is 01:00:01:20 at 25fps, so it starts before
+        // B-late even though its frames group is larger
+        let at_50fps = Timecode::from_str("01:00:01:40", FrameRate::Fps50).unwrap();
+        track.events.push(EDLTrackEvent::new(1, 5, "B-early", at_50fps, at_50fps + timecode("00:00:01:00")));
+
+        track.sort_events();
+        let names = track.events.iter().map(|event| event.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["A", "B-early", "B-late", "C-left", "C-left-tail", "C-right"]);
+    }
+
+    #[test]
+    fn track_channels() {
+        let mut track = EDLTrack::with_name("MX");
+        assert!(track.channels().is_empty());
+
+        for channel in [2, 1, 2, 1] {
+            let mut stereo_event = event("01:00:00:00", "01:00:04:00");
+            stereo_event.channel = channel;
+            track.events.push(stereo_event);
+        }
+
+        assert_eq!(track.channels(), [1, 2]);
+    }
+
+    #[test]
+    fn event_into_time_range() {
+        let pal_event = event("01:00:00:00", "01:00:02:00");
+        let ntsc_event = EDLTrackEvent {
+            time_in: Timecode::from_str("01:00:01:00", FrameRate::Fps30(false)).unwrap(),
+            time_out: Timecode::from_str("01:00:03:00", FrameRate::Fps30(false)).unwrap(),
+            ..pal_event.clone()
+        };
+
+        let pal_range = TimeRange::from(&pal_event);
+        assert_eq!(pal_range.start_ticks, 3_600_000_000_000);
+        assert_eq!(pal_range.duration_ticks(), 2_000_000_000);
+        assert_eq!(pal_range.intersect(&TimeRange::from(&ntsc_event)), Some(TimeRange::new(3_601_000_000_000, 3_602_000_000_000)));
+    }
+
+    #[test]
+    fn track_overlaps() {
+        let mut track = EDLTrack::with_name("DIA");
+        track.events.push(event("01:00:00:00", "01:00:04:00"));
+        track.events.push(event("01:00:02:00", "01:00:06:00"));
+        track.events.push(event("01:00:06:00", "01:00:08:00"));
+        track.events.push(event("01:00:03:00", "01:00:03:00"));
+        track.events.push(event("01:00:10:00", "01:00:12:00"));
+
+        let mut other_channel = event("01:00:01:00", "01:00:02:00");
+        other_channel.channel = 2;
+        track.events.push(other_channel);
+
+        assert_eq!(track.overlaps(), [(0, 1)]);
+
+        // 01:00:04:22 at 30fps starts at 4.733s, before the 25fps event ends
+        // at 4.8s, though its frames group is larger
+        let pal_event = event("01:00:04:00", "01:00:04:20");
+        let ntsc_event = EDLTrackEvent {
+            time_in: ntsc_timecode("01:00:04:22"),
+            time_out: ntsc_timecode("01:00:05:00"),
+            ..event("01:00:00:00", "01:00:00:00")
+        };
+        assert!(pal_event.overlaps(&ntsc_event));
+        assert!(ntsc_event.overlaps(&pal_event));
+    }
+
+    #[test]
+    fn track_gaps() {
+        let mut track = EDLTrack::with_name("DIA");
+        track.events.push(event("01:00:08:00", "01:00:10:00"));
+        track.events.push(event("01:00:00:00", "01:00:05:00"));
+        track.events.push(event("01:00:01:00", "01:00:03:00"));
+        track.events.push(event("01:00:06:00", "01:00:06:00"));
+
+        let mut other_channel = event("01:00:05:00", "01:00:08:00");
+        other_channel.channel = 2;
+        track.events.push(other_channel);
+
+        let gaps = track.gaps(1);
+        assert_eq!(gaps, [(timecode("01:00:05:00"), timecode("01:00:08:00"))]);
+        assert_eq!((gaps[0].1 - gaps[0].0).to_frames(), 75);
+        assert!(track.gaps(2).is_empty());
+        assert!(track.gaps(3).is_empty());
+
+        track.events.push(event("01:00:05:00", "01:00:08:00"));
+        assert!(track.gaps(1).is_empty());
+    }
+
+    #[test]
+    fn track_merge_overlaps() {
+        let mut track = EDLTrack::with_name("DIA");
+        track.events.push(event("01:00:02:00", "01:00:06:00"));
+        track.events.push(event("01:00:00:00", "01:00:04:00"));
+        track.events.push(event("01:00:05:00", "01:00:07:00"));
+        track.events.push(event("01:00:07:00", "01:00:08:00"));
+        track.events.push(event("01:00:10:00", "01:00:12:00"));
+
+        track.merge_overlaps();
+
+        let ranges = track.events.iter().map(EDLTrackEvent::time_range).collect::<Vec<_>>();
+        assert_eq!(ranges, [
+            (timecode("01:00:00:00"), timecode("01:00:07:00")),
+            (timecode("01:00:07:00"), timecode("01:00:08:00")),
+            (timecode("01:00:10:00"), timecode("01:00:12:00")),
+        ]);
+        assert!(track.overlaps().is_empty());
+
+        // The 30fps event starts first, so the 25fps event merges into it and
+        // its time_out is converted to 30fps
+        let mut mixed_rates = EDLTrack::with_name("MX");
+        mixed_rates.events.push(event("01:00:04:20", "01:00:06:00"));
+        mixed_rates.events.push(EDLTrackEvent {
+            time_in: ntsc_timecode("01:00:04:22"),
+            time_out: ntsc_timecode("01:00:04:25"),
+            ..event("01:00:00:00", "01:00:00:00")
+        });
+
+        mixed_rates.merge_overlaps();
+
+        let ranges = mixed_rates.events.iter().map(|event| (event.time_in, event.time_out)).collect::<Vec<_>>();
+        assert_eq!(ranges, [(ntsc_timecode("01:00:04:22"), ntsc_timecode("01:00:06:00"))]);
+    }
 }
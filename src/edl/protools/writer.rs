@@ -0,0 +1,373 @@
+// Copyright (C) Stefan Olivier
+// <https://stefanolivier.com>
+
+use std::io::Write;
+
+use crate::edl::protools::*;
+use crate::chrono::{
+    Timecode,
+};
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLSession` Writer Constants --
+//
+///////////////////////////////////////////////////////////////////////////
+
+const EDL_SECTION_SEPARATOR: &str = "\n\n";
+const EDL_FILES_TABLE_HEADER: &str = "Filename\tLocation";
+const EDL_CLIPS_TABLE_HEADER: &str = "CLIP NAME\tSource File";
+const EDL_PLUGINS_TABLE_HEADER: &str = "MANUFACTURER\tPLUG-IN NAME\tVERSION\tFORMAT\tSTEMS\tNUMBER OF INSTANCES";
+const EDL_MARKERS_TABLE_HEADER: &str = "#\tLOCATION\tTIME REFERENCE\tUNITS\tNAME\tCOMMENTS";
+const CSV_EVENTS_HEADER: [&str; 8] = ["Track Name", "Channel", "Event", "Clip Name", "Time In", "Time Out", "Duration", "State"];
+const CSV_LINE_TERMINATOR: &str = "\r\n";
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLSession` Writer Implementation --
+//
+///////////////////////////////////////////////////////////////////////////
+
+impl EDLSession {
+    /// Serializes the session as Protools EDL text
+    ///
+    /// Listing sections without any rows are omitted unless the session was
+    /// parsed from an EDL that had them (see the `EDLSESSION_FLAG_CONTAINS`
+    /// flags). The track listing is always written.
+    pub fn to_edl_string(&self) -> String {
+        let mut edl_bytes = Vec::<u8>::new();
+        self.write_edl(&mut edl_bytes).expect("writing an EDL to memory should not fail");
+        String::from_utf8(edl_bytes).expect("EDL text should be valid UTF-8")
+    }
+
+    /// Writes the session as Protools EDL text to `writer`
+    pub fn write_edl<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.write_header(writer)?;
+
+        if !self.plugins.is_empty() || self.check_flag(EDLSESSION_FLAG_CONTAINS_PLUGIN) {
+            write_section_banner(writer, EDLSection::PluginsListing)?;
+            writeln!(writer, "{}", EDL_PLUGINS_TABLE_HEADER)?;
+            for plugin in self.plugins.iter() {
+                writeln!(
+                    writer,
+                    "{}\t{}\t{}\t{}\t{}\t{}",
+                    plugin.manufacturer,
+                    plugin.name,
+                    plugin.version,
+                    plugin.format.as_str(),
+                    plugin.stems,
+                    plugin.instances,
+                )?;
+            }
+        }
+
+        if !self.files.online_files.is_empty() || self.check_flag(EDLSESSION_FLAG_CONTAINS_ONLINE_FILES) {
+            write_media_files(writer, EDLSection::OnlineFiles, &self.files.online_files)?;
+        }
+
+        if !self.files.offline_files.is_empty() || self.check_flag(EDLSESSION_FLAG_CONTAINS_OFFLINE_FILES) {
+            write_media_files(writer, EDLSection::OfflineFiles, &self.files.offline_files)?;
+        }
+
+        if !self.files.online_clips.is_empty() || self.check_flag(EDLSESSION_FLAG_CONTAINS_ONLINE_CLIPS) {
+            write_section_banner(writer, EDLSection::OnlineClips)?;
+            writeln!(writer, "{}", EDL_CLIPS_TABLE_HEADER)?;
+            for clip in self.files.online_clips.iter() {
+                writeln!(writer, "{}\t{}", clip.clip_name, clip.source_file)?;
+            }
+        }
+
+        write_section_banner(writer, EDLSection::TrackListing)?;
+        for (i, track) in self.tracks.iter().enumerate() {
+            if i > 0 { write!(writer, "{}", EDL_SECTION_SEPARATOR)?; }
+            self.write_track(writer, track)?;
+        }
+
+        if !self.markers.is_empty() || self.check_flag(EDLSESSION_FLAG_CONTAINS_MARKERS) {
+            write_section_banner(writer, EDLSection::MarkersListing)?;
+            writeln!(writer, "{}", EDL_MARKERS_TABLE_HEADER)?;
+            for marker in self.markers.iter() {
+                writeln!(
+                    writer,
+                    "{}\t{}\t{}\t{}\t{}\t{}",
+                    marker.id,
+                    marker.location.to_edl_string(),
+                    marker.time_reference,
+                    marker.unit.as_str(),
+                    marker.name,
+                    marker.comment,
+                )?;
+            }
+        }
+
+        for (banner, lines) in self.unknown_sections.iter() {
+            write!(writer, "{}", EDL_SECTION_SEPARATOR)?;
+            writeln!(writer, "{}", banner)?;
+            for line in lines.iter() {
+                writeln!(writer, "{}", line)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_header<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        write_field(writer, EDLField::SessionName, &self.name)?;
+        write_field(writer, EDLField::SessionSampleRate, &self.sample_rate.to_string())?;
+        write_field(writer, EDLField::SessionBitDepth, &self.bit_depth.to_string())?;
+        write_field(writer, EDLField::SessionStartTimecode, &self.start_timecode.to_string())?;
+        write_field(writer, EDLField::SessionTimecodeFormat, self.fps.to_edl_string())?;
+        write_field(writer, EDLField::SessionNumAudioTracks, &self.num_audio_tracks.to_string())?;
+        write_field(writer, EDLField::SessionNumAudioClips, &self.num_audio_clips.to_string())?;
+        write_field(writer, EDLField::SessionNumAudioFiles, &self.num_audio_files.to_string())?;
+
+        for (field_name, field_value) in self.extra_header.iter() {
+            writeln!(writer, "{}:\t{}", field_name, field_value)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_track<W: Write>(&self, writer: &mut W, track: &EDLTrack) -> std::io::Result<()> {
+        write_field(writer, EDLField::TrackName, &track.name)?;
+        write_field(writer, EDLField::TrackComment, &track.comment)?;
+        write_field(writer, EDLField::TrackDelay, &track.delay.to_edl_string())?;
+        write_field(writer, EDLField::TrackState, &track.state.to_edl_string())?;
+
+        if !self.plugins.is_empty() || !track.plugins.is_empty() {
+            write_field(writer, EDLField::TrackPlugins, &track.plugins.join("\t"))?;
+        }
+
+        let contains_timestamp = track.events
+            .iter()
+            .any(|event| event.check_flag(EDLEVENT_FLAG_HAS_TIMESTAMP) || event.timestamp != Timecode::with_fps(event.timestamp.frame_rate()));
+
+        use EDLTrackEventColumn::*;
+        let columns = [Channel, Event, ClipName, StartTime, EndTime, Duration, Timestamp, State];
+        let header = columns
+            .iter()
+            .filter(|&&column| contains_timestamp || column != Timestamp)
+            .map(|column| column.column_name())
+            .collect::<Vec<_>>()
+            .join("\t");
+        writeln!(writer, "{}", header)?;
+
+        for event in track.events.iter() {
+            write!(
+                writer,
+                "{}\t{}\t{}\t{}\t{}\t{}\t",
+                event.channel,
+                event.event,
+                event.name,
+                event.time_in,
+                event.time_out,
+                event.duration,
+            )?;
+
+            if contains_timestamp {
+                write!(writer, "{}\t", event.timestamp)?;
+            }
+
+            writeln!(writer, "{}", if event.is_muted { "Muted" } else { "Unmuted" })?;
+        }
+
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLSession` CSV Writer Implementation --
+//
+///////////////////////////////////////////////////////////////////////////
+
+impl EDLSession {
+    /// Exports the session's events as RFC 4180 CSV, one row per event in
+    /// track order, below a header row
+    pub fn to_events_csv(&self) -> String {
+        let mut csv_bytes = Vec::<u8>::new();
+        self.write_events_csv(&mut csv_bytes).expect("writing CSV to memory should not fail");
+        String::from_utf8(csv_bytes).expect("CSV text should be valid UTF-8")
+    }
+
+    /// Writes the session's events as RFC 4180 CSV to `writer`
+    pub fn write_events_csv<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        write_csv_record(writer, &CSV_EVENTS_HEADER)?;
+
+        for (track, event) in self.events() {
+            write_csv_record(writer, &[
+                track.name.as_str(),
+                &event.channel.to_string(),
+                &event.event.to_string(),
+                &event.name,
+                &event.time_in.to_string(),
+                &event.time_out.to_string(),
+                &event.duration.to_string(),
+                if event.is_muted { "Muted" } else { "Unmuted" },
+            ])?;
+        }
+
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLSession` Writer Helpers --
+//
+///////////////////////////////////////////////////////////////////////////
+
+fn write_field<W: Write>(writer: &mut W, field: EDLField, value: &str) -> std::io::Result<()> {
+    writeln!(writer, "{}:\t{}", field.field_name(), value)
+}
+
+fn write_section_banner<W: Write>(writer: &mut W, section: EDLSection) -> std::io::Result<()> {
+    write!(writer, "{}", EDL_SECTION_SEPARATOR)?;
+    writeln!(writer, "{}", section.section_name())
+}
+
+/// Writes one CSV record, quoting fields that contain a comma, a double
+/// quote or a line break, and doubling any double quotes inside them
+fn write_csv_record<W: Write>(writer: &mut W, fields: &[&str]) -> std::io::Result<()> {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 { write!(writer, ",")?; }
+
+        if field.contains([',', '"', '\r', '\n']) {
+            write!(writer, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            write!(writer, "{}", field)?;
+        }
+    }
+
+    write!(writer, "{}", CSV_LINE_TERMINATOR)
+}
+
+fn write_media_files<W: Write>(writer: &mut W, section: EDLSection, media_files: &[EDLMediaFile]) -> std::io::Result<()> {
+    write_section_banner(writer, section)?;
+    writeln!(writer, "{}", EDL_FILES_TABLE_HEADER)?;
+    for media_file in media_files {
+        writeln!(writer, "{}\t{}", media_file.file_name, media_file.location.as_deref().unwrap_or_default())?;
+    }
+
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `EDLSession` Writer Unit Tests --
+//
+///////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::FrameRate;
+
+    const FIXTURE_BASIC_SESSION: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/protools/basic_session.txt");
+
+    fn reparse(session: &EDLSession) -> EDLSession {
+        let (reparsed, _) = EDLParser::parse_bytes(session.to_edl_string().as_bytes(), encoding_rs::UTF_8).expect("serialized EDL should parse");
+        reparsed
+    }
+
+    #[test]
+    fn round_trip_basic_session() {
+        let session = EDLParser::parse(FIXTURE_BASIC_SESSION, encoding_rs::UTF_8).expect("basic session fixture should parse");
+        let reparsed = reparse(&session);
+
+        assert_eq!(reparsed.name, session.name);
+        assert_eq!(reparsed.sample_rate, session.sample_rate);
+        assert_eq!(reparsed.bit_depth, session.bit_depth);
+        assert_eq!(reparsed.start_timecode, session.start_timecode);
+        assert_eq!(reparsed.fps, session.fps);
+        assert_eq!(reparsed.num_audio_tracks, session.num_audio_tracks);
+        assert_eq!(reparsed.num_audio_clips, session.num_audio_clips);
+        assert_eq!(reparsed.num_audio_files, session.num_audio_files);
+        assert_eq!(reparsed.files, session.files);
+        assert_eq!(reparsed.plugins, session.plugins);
+        assert_eq!(reparsed.tracks, session.tracks);
+        assert_eq!(reparsed.markers, session.markers);
+        assert_eq!(reparsed.to_edl_string(), session.to_edl_string());
+    }
+
+    #[test]
+    fn round_trip_duration_mismatch() {
+        let text = std::fs::read_to_string(FIXTURE_BASIC_SESSION).expect("basic session fixture should be readable");
+        let mismatched_text = text.replacen("00:00:18:00", "00:00:18:01", 1);
+        let (session, _) = EDLParser::parse_bytes(mismatched_text.as_bytes(), encoding_rs::UTF_8).expect("a duration mismatch should not fail the parse");
+
+        let (reparsed, warnings) = EDLParser::parse_bytes(session.to_edl_string().as_bytes(), encoding_rs::UTF_8).expect("serialized EDL should parse");
+        assert_eq!(reparsed.tracks[1].events[0].duration, Timecode::from_str("00:00:18:01", FrameRate::Fps25).unwrap());
+        assert_eq!(reparsed.tracks, session.tracks);
+        assert!(matches!(warnings.as_slice(), [EDLWarning::DurationMismatch { .. }]));
+        assert!(session.to_events_csv().contains(",00:00:18:01,"));
+    }
+
+    #[test]
+    fn section_banners_match_parser() {
+        let session = EDLParser::parse(FIXTURE_BASIC_SESSION, encoding_rs::UTF_8).expect("basic session fixture should parse");
+        let edl_string = session.to_edl_string();
+
+        for section in [EDLSection::PluginsListing, EDLSection::OnlineFiles, EDLSection::OfflineFiles, EDLSection::OnlineClips, EDLSection::TrackListing, EDLSection::MarkersListing] {
+            assert!(edl_string.lines().any(|line| line == section.section_name()));
+        }
+
+        assert!(edl_string.starts_with("SESSION NAME:\tTimeline Kit Demo\n"));
+    }
+
+    #[test]
+    fn files_and_clips_sections_round_trip() {
+        let session = EDLSession::builder()
+            .name("Files Session")
+            .add_online_file(EDLMediaFile { file_name: "Dialog_01.wav".to_string(), location: Some("Macintosh HD:Audio Files:".to_string()), ..EDLMediaFile::default() })
+            .add_online_file(EDLMediaFile { file_name: "Music_01.wav".to_string(), location: Some("Macintosh HD:Audio Files:".to_string()), ..EDLMediaFile::default() })
+            .add_offline_file(EDLMediaFile { file_name: "Missing_01.wav".to_string(), location: None, ..EDLMediaFile::default() })
+            .add_online_clip(EDLClip { clip_name: "Dialog_01-01".to_string(), source_file: "Dialog_01.wav".to_string(), ..EDLClip::default() })
+            .build();
+
+        let edl_string = session.to_edl_string();
+        let expected_sections = [
+            format!("{}\n{}\nDialog_01.wav\tMacintosh HD:Audio Files:\nMusic_01.wav\tMacintosh HD:Audio Files:\n", EDLSection::OnlineFiles.section_name(), EDL_FILES_TABLE_HEADER),
+            format!("{}\n{}\nMissing_01.wav\t\n", EDLSection::OfflineFiles.section_name(), EDL_FILES_TABLE_HEADER),
+            format!("{}\n{}\nDialog_01-01\tDialog_01.wav\n", EDLSection::OnlineClips.section_name(), EDL_CLIPS_TABLE_HEADER),
+        ];
+
+        for expected_section in expected_sections.iter() {
+            assert!(edl_string.contains(expected_section.as_str()), "missing section:\n{}", expected_section);
+        }
+
+        let reparsed = reparse(&session);
+        assert_eq!(reparsed.files, session.files);
+    }
+
+    #[test]
+    fn empty_sections_round_trip() {
+        let edl_text = std::fs::read_to_string(FIXTURE_BASIC_SESSION).expect("basic session fixture should be readable");
+        let markers_start = edl_text.find(EDLSection::MarkersListing.section_name()).expect("basic session fixture should have a markers section");
+        let markers_header_end = markers_start + edl_text[markers_start..].match_indices('\n').nth(1).map(|(i, _)| i + 1).unwrap();
+
+        let (without_markers, _) = EDLParser::parse_bytes(&edl_text.as_bytes()[..markers_start], encoding_rs::UTF_8).expect("session without markers should parse");
+        let (empty_markers, _) = EDLParser::parse_bytes(&edl_text.as_bytes()[..markers_header_end], encoding_rs::UTF_8).expect("session with an empty markers section should parse");
+
+        assert!(!without_markers.to_edl_string().contains(EDLSection::MarkersListing.section_name()));
+        assert!(empty_markers.to_edl_string().ends_with(&format!("{}\n{}\n", EDLSection::MarkersListing.section_name(), EDL_MARKERS_TABLE_HEADER)));
+
+        let reparsed = reparse(&empty_markers);
+        assert!(reparsed.markers.is_empty());
+        assert!(reparsed.check_flag(EDLSESSION_FLAG_CONTAINS_MARKERS));
+    }
+
+    #[test]
+    fn events_csv() {
+        let mut session = EDLParser::parse(FIXTURE_BASIC_SESSION, encoding_rs::UTF_8).expect("basic session fixture should parse");
+        session.tracks[1].events[1].name = "Music_01, \"Alt\"".to_string();
+
+        let csv = session.to_events_csv();
+        let rows = csv.split_terminator("\r\n").collect::<Vec<_>>();
+
+        assert_eq!(rows.len(), 5);
+        assert_eq!(rows[0], "Track Name,Channel,Event,Clip Name,Time In,Time Out,Duration,State");
+        assert_eq!(rows[2], "DIA,1,2,Dialog_01-02,01:00:06:00,01:00:10:12,00:00:04:12,Muted");
+        assert_eq!(rows[4], "MX,2,1,\"Music_01, \"\"Alt\"\"\",01:00:02:00,01:00:20:00,00:00:18:00,Unmuted");
+    }
+}
@@ -4,8 +4,15 @@
 #![allow(dead_code)]
 
 use crate::edl::EDLParseField;
+use crate::format::FormatParseError;
 
-#[derive(Debug, Default, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
+/// Audio sample rates
+///
+/// Rates outside the common set, such as the pull-up and pull-down rates
+/// used when conforming to video, are kept as their exact value in `Other`.
+/// Sample rates compare and hash by their value in Hz, so
+/// `SampleRate::Other(48000.0)` is equal to `SampleRate::Khz48`.
+#[derive(Debug, Default, Clone, Copy)]
 pub enum SampleRate {
     Khz22,
     #[default]
@@ -14,23 +21,109 @@ pub enum SampleRate {
     Khz88p2,
     Khz96,
     Khz192,
+    Other(f64),
+}
+
+impl SampleRate {
+    const KNOWN_VARIANTS: [SampleRate; 6] = [
+        SampleRate::Khz22,
+        SampleRate::Khz44p1,
+        SampleRate::Khz48,
+        SampleRate::Khz88p2,
+        SampleRate::Khz96,
+        SampleRate::Khz192,
+    ];
+
+    /// Maps a rate in Hz to its named variant, or to `Other` for any other
+    /// finite, positive rate
+    pub fn from_hz(hz: f64) -> Option<Self> {
+        if !hz.is_finite() || hz <= 0.0 {
+            return None;
+        }
+
+        let known_rate = SampleRate::KNOWN_VARIANTS
+            .iter()
+            .copied()
+            .find(|sample_rate| sample_rate.as_f64() == hz);
+
+        Some(known_rate.unwrap_or(SampleRate::Other(hz)))
+    }
+
+    /// The sample rate in samples per second, rounded to the nearest whole
+    /// sample for `Other` rates
+    pub fn as_hz(&self) -> u32 {
+        match *self {
+            SampleRate::Khz22 => 22000,
+            SampleRate::Khz44p1 => 44100,
+            SampleRate::Khz48 => 48000,
+            SampleRate::Khz88p2 => 88200,
+            SampleRate::Khz96 => 96000,
+            SampleRate::Khz192 => 192000,
+            SampleRate::Other(hz) => hz.round() as u32,
+        }
+    }
+
+    /// The exact sample rate in samples per second
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            SampleRate::Other(hz) => hz,
+            _ => self.as_hz() as f64,
+        }
+    }
+
+    pub fn as_f32(&self) -> f32 {
+        self.as_f64() as f32
+    }
+}
+
+impl PartialEq for SampleRate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for SampleRate {}
+
+impl PartialOrd for SampleRate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SampleRate {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_f64().total_cmp(&other.as_f64())
+    }
+}
+
+impl std::hash::Hash for SampleRate {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_f64().to_bits().hash(state);
+    }
 }
 
 impl EDLParseField<Self> for SampleRate {
+    /// Parses a sample rate in Hz, as written by Protools with six decimal
+    /// places (e.g. `"48000.000000"`) or as a bare number (e.g. `"44100"`)
     fn parse_field(field_string: &str) -> Option<Self> {
-        match field_string.trim() {
-            "22000.000000" => Some(SampleRate::Khz22),
-            "44100.000000" => Some(SampleRate::Khz44p1),
-            "48000.000000" => Some(SampleRate::Khz48),
-            "88200.000000" => Some(SampleRate::Khz88p2),
-            "96000.000000" => Some(SampleRate::Khz96),
-            "192000.000000" => Some(SampleRate::Khz192),
-            _ => None,
-        }
+        field_string.trim().parse::<f64>().ok().and_then(SampleRate::from_hz)
+    }
+}
+
+impl TryFrom<&str> for SampleRate {
+    type Error = FormatParseError;
+    fn try_from(sample_rate_string: &str) -> Result<Self, Self::Error> {
+        SampleRate::parse_field(sample_rate_string).ok_or_else(|| FormatParseError::UnknownSampleRate(sample_rate_string.trim().to_string()))
+    }
+}
+
+impl std::fmt::Display for SampleRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.6}", self.as_f64())
     }
 }
 
-#[derive(Debug, Default, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Default, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum BitDepth {
     Bit8,
     #[default]
@@ -42,6 +135,24 @@ pub enum BitDepth {
     Bit64Float,
 }
 
+impl BitDepth {
+    /// The number of bits per sample
+    pub fn bits(&self) -> u32 {
+        match *self {
+            BitDepth::Bit8 => 8,
+            BitDepth::Bit16 => 16,
+            BitDepth::Bit24 => 24,
+            BitDepth::Bit32 | BitDepth::Bit32Float => 32,
+            BitDepth::Bit64 | BitDepth::Bit64Float => 64,
+        }
+    }
+
+    /// Returns `true` if samples are stored as floating point values
+    pub fn is_float(&self) -> bool {
+        matches!(*self, BitDepth::Bit32Float | BitDepth::Bit64Float)
+    }
+}
+
 impl EDLParseField<Self> for BitDepth {
     fn parse_field(field_string: &str) -> Option<Self> {
         match field_string.trim() {
@@ -56,3 +167,146 @@ impl EDLParseField<Self> for BitDepth {
         }
     }
 }
+
+impl TryFrom<&str> for BitDepth {
+    type Error = FormatParseError;
+    fn try_from(bit_depth_string: &str) -> Result<Self, Self::Error> {
+        BitDepth::parse_field(bit_depth_string).ok_or_else(|| FormatParseError::UnknownBitDepth(bit_depth_string.trim().to_string()))
+    }
+}
+
+impl std::fmt::Display for BitDepth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-bit", self.bits())?;
+        if self.is_float() {
+            write!(f, " float")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SampleRate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SampleRate {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let sample_rate_string = String::deserialize(deserializer)?;
+        SampleRate::try_from(sample_rate_string.as_str()).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for BitDepth {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BitDepth {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bit_depth_string = String::deserialize(deserializer)?;
+        BitDepth::try_from(bit_depth_string.as_str()).map_err(serde::de::Error::custom)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `SampleRate` & `BitDepth` Unit Tests --
+//
+///////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(SampleRate::try_from("48000.000000"), Ok(SampleRate::Khz48));
+        assert_eq!(SampleRate::try_from("fast"), Err(FormatParseError::UnknownSampleRate("fast".to_string())));
+        assert_eq!(BitDepth::try_from("32-bit float"), Ok(BitDepth::Bit32Float));
+        assert_eq!(BitDepth::try_from(" 12-bit "), Err(FormatParseError::UnknownBitDepth("12-bit".to_string())));
+
+        let bit_depth: Result<BitDepth, _> = "24-bit".try_into();
+        assert_eq!(bit_depth, Ok(BitDepth::Bit24));
+    }
+
+    #[test]
+    fn sample_rate_as_hz() {
+        assert_eq!(SampleRate::Khz48.as_hz(), 48000);
+        assert_eq!(SampleRate::Khz44p1.as_hz(), 44100);
+        assert_eq!(SampleRate::Khz44p1.as_f32(), 44100.0);
+    }
+
+    #[test]
+    fn bit_depth_bits_and_is_float() {
+        assert_eq!(BitDepth::Bit24.bits(), 24);
+        assert!(!BitDepth::Bit24.is_float());
+        assert_eq!(BitDepth::Bit32.bits(), 32);
+        assert!(!BitDepth::Bit32.is_float());
+        assert_eq!(BitDepth::Bit32Float.bits(), 32);
+        assert!(BitDepth::Bit32Float.is_float());
+        assert_eq!(BitDepth::Bit64Float.bits(), 64);
+        assert!(BitDepth::Bit64Float.is_float());
+    }
+
+    #[test]
+    fn sample_rate_display_round_trip() {
+        let sample_rates = [
+            SampleRate::Khz22,
+            SampleRate::Khz44p1,
+            SampleRate::Khz48,
+            SampleRate::Khz88p2,
+            SampleRate::Khz96,
+            SampleRate::Khz192,
+        ];
+
+        for sample_rate in sample_rates {
+            assert_eq!(SampleRate::parse_field(&sample_rate.to_string()), Some(sample_rate));
+        }
+
+        assert_eq!(SampleRate::Khz48.to_string(), "48000.000000");
+    }
+
+    #[test]
+    fn sample_rate_other_values() {
+        assert!(matches!(SampleRate::parse_field("44100"), Some(SampleRate::Khz44p1)));
+
+        let pull_down = SampleRate::parse_field("47952.000000").expect("pull-down rate should parse");
+        assert!(matches!(pull_down, SampleRate::Other(hz) if hz == 47952.0));
+        assert_eq!(pull_down.as_hz(), 47952);
+        assert_eq!(pull_down.to_string(), "47952.000000");
+        assert_eq!(SampleRate::parse_field("44144.1").map(|sample_rate| sample_rate.as_f64()), Some(44144.1));
+
+        assert_eq!(SampleRate::Other(48000.0), SampleRate::Khz48);
+        assert!(SampleRate::Khz44p1 < pull_down && pull_down < SampleRate::Khz48);
+        assert_eq!(SampleRate::parse_field("48k"), None);
+        assert_eq!(SampleRate::parse_field("NaN"), None);
+        assert_eq!(SampleRate::parse_field("-48000"), None);
+    }
+
+    #[test]
+    fn bit_depth_display_round_trip() {
+        let bit_depths = [
+            BitDepth::Bit8,
+            BitDepth::Bit16,
+            BitDepth::Bit24,
+            BitDepth::Bit32,
+            BitDepth::Bit32Float,
+            BitDepth::Bit64,
+            BitDepth::Bit64Float,
+        ];
+
+        for bit_depth in bit_depths {
+            assert_eq!(BitDepth::parse_field(&bit_depth.to_string()), Some(bit_depth));
+        }
+
+        assert_eq!(BitDepth::Bit32Float.to_string(), "32-bit float");
+    }
+}
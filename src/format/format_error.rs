@@ -0,0 +1,27 @@
+// Copyright (C) Stefan Olivier
+// <https://stefanolivier.com>
+
+use thiserror::Error;
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `FormatParseError` Declaration --
+//
+///////////////////////////////////////////////////////////////////////////
+
+/// Errors from converting a string into one of the format enums, or into
+/// an `EDLUnit`, with `TryFrom<&str>`
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum FormatParseError {
+    #[error("unknown frame rate '{0}'")]
+    UnknownFrameRate(String),
+
+    #[error("unknown sample rate '{0}'")]
+    UnknownSampleRate(String),
+
+    #[error("unknown bit depth '{0}'")]
+    UnknownBitDepth(String),
+
+    #[error("unknown unit '{0}'")]
+    UnknownUnit(String),
+}
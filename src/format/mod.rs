@@ -4,6 +4,7 @@
 #![allow(unused_braces)]
 
 mod audio_format;
+mod format_error;
 mod video_format;
 
 pub use audio_format::{
@@ -11,6 +12,8 @@ pub use audio_format::{
     BitDepth,
 };
 
+pub use format_error::FormatParseError;
+
 pub use video_format::{
     FrameRate,
 };
@@ -4,53 +4,327 @@
 #![allow(dead_code)]
 
 use crate::edl::EDLParseField;
+use crate::format::FormatParseError;
 
-#[derive(Debug, Default, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
+const FRAMERATE_SIZE: usize = 12;
+
+/// Video frame rates
+///
+/// The `bool` on `Fps24`, `Fps30` and `Fps60` selects the 1001-based
+/// (drop-frame) rate, i.e. 23.976, 29.97 and 59.94. The `bool` on `Fps48`
+/// and `Fps120` selects the 1001-based 47.952 and 119.88 rates, which are
+/// counted with non-drop timecode. Protools EDLs are known to carry the
+/// 23.976, 29.97 and 59.94 rates; the `"47.952 Frame"` and `"119.88 Frame"`
+/// strings follow the same pattern but have not been seen in an export.
+///
+/// Frame rates are ordered by their numeric rate, not by declaration order.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum FrameRate {
     Fps24(bool),
     #[default]
     Fps25,
     Fps30(bool),
-    Fps48,
+    Fps48(bool),
     Fps50,
     Fps60(bool),
-    Fps120,
+    Fps120(bool),
 }
 
 impl FrameRate {
+    /// The frame rate in frames per second, rounded to the rate's usual
+    /// name, e.g. `23.976` for `Fps24(true)`
     pub fn as_float(&self) -> f32 {
-        match self {
-            &FrameRate::Fps24(is_dropframe) => if is_dropframe { 23.976 } else { 24.0 },
-            &FrameRate::Fps25 => 25.0,
-            &FrameRate::Fps30(is_dropframe) => if is_dropframe { 29.97 } else { 30.0 },
-            &FrameRate::Fps48 => 48.0,
-            &FrameRate::Fps50 => 50.0,
-            &FrameRate::Fps60(is_dropframe) => if is_dropframe { 59.94 } else { 60.0 },
-            &FrameRate::Fps120 => 120.0,
+        match *self {
+            FrameRate::Fps24(is_dropframe) => if is_dropframe { 23.976 } else { 24.0 },
+            FrameRate::Fps25 => 25.0,
+            FrameRate::Fps30(is_dropframe) => if is_dropframe { 29.97 } else { 30.0 },
+            FrameRate::Fps48(is_fractional) => if is_fractional { 47.952 } else { 48.0 },
+            FrameRate::Fps50 => 50.0,
+            FrameRate::Fps60(is_dropframe) => if is_dropframe { 59.94 } else { 60.0 },
+            FrameRate::Fps120(is_fractional) => if is_fractional { 119.88 } else { 120.0 },
+        }
+    }
+
+    pub const fn all_variants() -> &'static [FrameRate; FRAMERATE_SIZE] {
+        use FrameRate::*;
+        &[
+            Fps24(true),
+            Fps24(false),
+            Fps25,
+            Fps30(true),
+            Fps30(false),
+            Fps48(true),
+            Fps48(false),
+            Fps50,
+            Fps60(true),
+            Fps60(false),
+            Fps120(true),
+            Fps120(false),
+        ]
+    }
+
+    /// Returns `true` for the fractional rates that are counted with
+    /// drop-frame timecode
+    pub fn is_drop_frame(&self) -> bool {
+        matches!(*self, FrameRate::Fps24(true) | FrameRate::Fps30(true) | FrameRate::Fps60(true))
+    }
+
+    /// Maps a frame rate given as a bare number to its variant, e.g. `29.97`
+    /// to `Fps30(true)` and `30` to `Fps30(false)`
+    ///
+    /// Values within `0.01` of a known rate match it, so truncated or
+    /// rounded forms such as `23.98` or `29.970029` are accepted.
+    pub fn from_float(fps: f32) -> Option<Self> {
+        const FROM_FLOAT_TOLERANCE: f32 = 0.01;
+
+        FrameRate::all_variants()
+            .iter()
+            .copied()
+            .find(|frame_rate| (frame_rate.as_float() - fps).abs() <= FROM_FLOAT_TOLERANCE)
+    }
+
+    /// The whole number of frame labels per second used for frame math,
+    /// e.g. `30` for both 29.97 and 30
+    pub fn nominal(&self) -> u32 {
+        let (numerator, denominator) = self.as_rational();
+        numerator.div_ceil(denominator)
+    }
+
+    /// The frame rate in frames per second, computed from the exact
+    /// rational rate, e.g. `23.976023976...` for `Fps24(true)`
+    pub fn as_float_f64(&self) -> f64 {
+        let (numerator, denominator) = self.as_rational();
+        numerator as f64 / denominator as f64
+    }
+
+    /// The exact frame rate as a `(numerator, denominator)` pair of frames
+    /// per second, e.g. `(30000, 1001)` for 29.97
+    pub fn as_rational(&self) -> (u32, u32) {
+        match *self {
+            FrameRate::Fps24(is_dropframe) => if is_dropframe { (24000, 1001) } else { (24, 1) },
+            FrameRate::Fps25 => (25, 1),
+            FrameRate::Fps30(is_dropframe) => if is_dropframe { (30000, 1001) } else { (30, 1) },
+            FrameRate::Fps48(is_fractional) => if is_fractional { (48000, 1001) } else { (48, 1) },
+            FrameRate::Fps50 => (50, 1),
+            FrameRate::Fps60(is_dropframe) => if is_dropframe { (60000, 1001) } else { (60, 1) },
+            FrameRate::Fps120(is_fractional) => if is_fractional { (120000, 1001) } else { (120, 1) },
+        }
+    }
+
+    /// The frame rate as written in the `TIMECODE FORMAT` field of a
+    /// Protools EDL, which `FrameRate::parse_field` accepts back
+    pub fn to_edl_string(&self) -> &'static str {
+        match *self {
+            FrameRate::Fps24(is_dropframe) => if is_dropframe { "23.976 Drop Frame" } else { "24 Frame" },
+            FrameRate::Fps25 => "25 Frame",
+            FrameRate::Fps30(is_dropframe) => if is_dropframe { "29.97 Drop Frame" } else { "30 Frame" },
+            FrameRate::Fps48(is_fractional) => if is_fractional { "47.952 Frame" } else { "48 Frame" },
+            FrameRate::Fps50 => "50 Frame",
+            FrameRate::Fps60(is_dropframe) => if is_dropframe { "59.94 Drop Frame" } else { "60 Frame" },
+            FrameRate::Fps120(is_fractional) => if is_fractional { "119.88 Frame" } else { "120 Frame" },
         }
     }
 }
 
 impl EDLParseField<Self> for FrameRate {
+    /// Parses a frame rate from the canonical Protools strings (e.g.
+    /// `"29.97 Drop Frame"`, `"25 Frame"`), the bare numeric form (e.g.
+    /// `"25"`), or the numeric form with a `DF`/`NDF` abbreviation
     fn parse_field(fps_string: &str) -> Option<Self> { // TODO: Better error reporting
-        match fps_string.trim() {
-            "23.976 Drop Frame" => Some(FrameRate::Fps24(true)),
-            "24 Frame" => Some(FrameRate::Fps24(false)),
-            "25 Frame" => Some(FrameRate::Fps25),
-            "29.97 Drop Frame" => Some(FrameRate::Fps30(true)),
-            "30 Frame" => Some(FrameRate::Fps30(false)),
-            "48 Frame" => Some(FrameRate::Fps48),
-            "50 Frame" => Some(FrameRate::Fps50),
-            "59.94 Drop Frame" => Some(FrameRate::Fps60(true)),
-            "60 Frame" => Some(FrameRate::Fps60(false)),
-            "120 Frame" => Some(FrameRate::Fps120),
+        let fps_string = fps_string.trim();
+        let (rate, suffix) = fps_string.split_once(' ').unwrap_or((fps_string, ""));
+        let is_drop_frame = match suffix.trim() {
+            "Drop Frame" | "DF" => true,
+            "Frame" | "NDF" => false,
+            // The 1001-based rates are only modelled as drop-frame, so a bare
+            // 23.976, 29.97 or 59.94 is read as drop-frame, while an explicit
+            // non-drop suffix on them is rejected below
+            "" => matches!(rate, "23.976" | "29.97" | "59.94"),
+            _ => return None,
+        };
+
+        match (rate, is_drop_frame) {
+            ("23.976", true) | ("24", true) => Some(FrameRate::Fps24(true)),
+            ("24", false) => Some(FrameRate::Fps24(false)),
+            ("25", false) => Some(FrameRate::Fps25),
+            ("29.97", true) | ("30", true) => Some(FrameRate::Fps30(true)),
+            ("30", false) => Some(FrameRate::Fps30(false)),
+            ("47.952", false) => Some(FrameRate::Fps48(true)),
+            ("48", false) => Some(FrameRate::Fps48(false)),
+            ("50", false) => Some(FrameRate::Fps50),
+            ("59.94", true) | ("60", true) => Some(FrameRate::Fps60(true)),
+            ("60", false) => Some(FrameRate::Fps60(false)),
+            ("119.88", false) => Some(FrameRate::Fps120(true)),
+            ("120", false) => Some(FrameRate::Fps120(false)),
             _ => None,
         }
     }
 }
 
+impl TryFrom<&str> for FrameRate {
+    type Error = FormatParseError;
+    fn try_from(fps_string: &str) -> Result<Self, Self::Error> {
+        FrameRate::parse_field(fps_string).ok_or_else(|| FormatParseError::UnknownFrameRate(fps_string.trim().to_string()))
+    }
+}
+
+impl PartialOrd for FrameRate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FrameRate {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_float().total_cmp(&other.as_float())
+            .then_with(|| self.is_drop_frame().cmp(&other.is_drop_frame()))
+    }
+}
+
 impl std::fmt::Display for FrameRate {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_float())
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for FrameRate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.to_edl_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FrameRate {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fps_string = String::deserialize(deserializer)?;
+        FrameRate::try_from(fps_string.as_str()).map_err(serde::de::Error::custom)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+//
+//  -- @SECTION `FrameRate` Unit Tests --
+//
+///////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(FrameRate::try_from("29.97 Drop Frame"), Ok(FrameRate::Fps30(true)));
+        assert_eq!(FrameRate::try_from("31 Frame"), Err(FormatParseError::UnknownFrameRate("31 Frame".to_string())));
+        assert_eq!(FormatParseError::UnknownFrameRate("31 Frame".to_string()).to_string(), "unknown frame rate '31 Frame'");
+    }
+
+    #[test]
+    fn ordering_by_rate() {
+        use FrameRate::*;
+        let mut frame_rates = vec![Fps60(false), Fps25, Fps120(true), Fps24(false), Fps50, Fps30(true), Fps48(false), Fps24(true), Fps60(true), Fps30(false), Fps120(false), Fps48(true)];
+        frame_rates.sort();
+
+        assert_eq!(frame_rates, FrameRate::all_variants());
+        assert!(frame_rates.windows(2).all(|pair| pair[0].as_float() < pair[1].as_float()));
+        assert!(Fps48(false) < Fps50);
+        assert!(Fps25 < Fps30(true));
+    }
+
+    #[test]
+    fn parse_field_canonical() {
+        assert_eq!(FrameRate::parse_field("29.97 Drop Frame"), Some(FrameRate::Fps30(true)));
+        assert_eq!(FrameRate::parse_field("25 Frame"), Some(FrameRate::Fps25));
+    }
+
+    #[test]
+    fn parse_field_bare_and_abbreviated() {
+        assert_eq!(FrameRate::parse_field("25"), Some(FrameRate::Fps25));
+        assert_eq!(FrameRate::parse_field("29.97 DF"), Some(FrameRate::Fps30(true)));
+        assert_eq!(FrameRate::parse_field("30 NDF"), Some(FrameRate::Fps30(false)));
+        assert_eq!(FrameRate::parse_field("25 DF"), None);
+        assert_eq!(FrameRate::parse_field("25 Frames Per Second"), None);
+    }
+
+    #[test]
+    fn parse_field_fractional_rates_require_drop_frame() {
+        assert_eq!(FrameRate::parse_field("29.97"), Some(FrameRate::Fps30(true)));
+        assert_eq!(FrameRate::parse_field("23.976 DF"), Some(FrameRate::Fps24(true)));
+        assert_eq!(FrameRate::parse_field("29.97 Frame"), None);
+        assert_eq!(FrameRate::parse_field("29.97 NDF"), None);
+        assert_eq!(FrameRate::parse_field("23.976 Frame"), None);
+        assert_eq!(FrameRate::parse_field("59.94 NDF"), None);
+    }
+
+    #[test]
+    fn to_edl_string_round_trip() {
+        for &fps in FrameRate::all_variants() {
+            assert_eq!(FrameRate::parse_field(fps.to_edl_string()), Some(fps));
+        }
+    }
+
+    #[test]
+    fn as_float_f64_exact_rates() {
+        assert_eq!(FrameRate::Fps24(true).as_float_f64(), 24000.0 / 1001.0);
+        assert_eq!(FrameRate::Fps25.as_float_f64(), 25.0);
+        assert!((FrameRate::Fps30(true).as_float_f64() - 29.97).abs() < 1e-4);
+        assert_eq!(FrameRate::Fps24(true).nominal(), 24);
+    }
+
+    #[test]
+    fn as_rational_exact_rates() {
+        assert_eq!(FrameRate::Fps24(true).as_rational(), (24000, 1001));
+        assert_eq!(FrameRate::Fps30(true).as_rational(), (30000, 1001));
+        assert_eq!(FrameRate::Fps60(true).as_rational(), (60000, 1001));
+        assert_eq!(FrameRate::Fps25.as_rational(), (25, 1));
+    }
+
+    #[test]
+    fn from_float_and_nominal() {
+        assert_eq!(FrameRate::from_float(23.976), Some(FrameRate::Fps24(true)));
+        assert_eq!(FrameRate::from_float(23.98), Some(FrameRate::Fps24(true)));
+        assert_eq!(FrameRate::from_float(29.97), Some(FrameRate::Fps30(true)));
+        assert_eq!(FrameRate::from_float(59.94), Some(FrameRate::Fps60(true)));
+        assert_eq!(FrameRate::from_float(24.0), Some(FrameRate::Fps24(false)));
+        assert_eq!(FrameRate::from_float(30.0), Some(FrameRate::Fps30(false)));
+        assert_eq!(FrameRate::from_float(120.0), Some(FrameRate::Fps120(false)));
+        assert_eq!(FrameRate::from_float(47.952), Some(FrameRate::Fps48(true)));
+        assert_eq!(FrameRate::from_float(119.88), Some(FrameRate::Fps120(true)));
+        assert_eq!(FrameRate::from_float(29.5), None);
+
+        assert_eq!(FrameRate::Fps24(true).nominal(), 24);
+        assert_eq!(FrameRate::Fps30(true).nominal(), 30);
+        assert_eq!(FrameRate::Fps60(false).nominal(), 60);
+        assert_eq!(FrameRate::Fps25.nominal(), 25);
+    }
+
+    #[test]
+    fn is_drop_frame_variants() {
+        let drop_frame_rates = [FrameRate::Fps24(true), FrameRate::Fps30(true), FrameRate::Fps60(true)];
+        let non_drop_frame_rates = [
+            FrameRate::Fps24(false),
+            FrameRate::Fps25,
+            FrameRate::Fps30(false),
+            FrameRate::Fps48(true),
+            FrameRate::Fps48(false),
+            FrameRate::Fps50,
+            FrameRate::Fps60(false),
+            FrameRate::Fps120(true),
+            FrameRate::Fps120(false),
+        ];
+
+        assert!(drop_frame_rates.iter().all(FrameRate::is_drop_frame));
+        assert!(!non_drop_frame_rates.iter().any(FrameRate::is_drop_frame));
+    }
+
+    #[test]
+    fn fractional_high_frame_rates() {
+        assert_eq!(FrameRate::parse_field("47.952 Frame"), Some(FrameRate::Fps48(true)));
+        assert_eq!(FrameRate::parse_field("119.88"), Some(FrameRate::Fps120(true)));
+        assert_eq!(FrameRate::parse_field("48 DF"), None);
+        assert_eq!(FrameRate::Fps48(true).as_rational(), (48000, 1001));
+        assert_eq!(FrameRate::Fps120(true).nominal(), 120);
+        assert_eq!(format!("{}", FrameRate::Fps48(true)), "47.952");
+        assert_eq!(format!("{}", FrameRate::Fps120(true)), "119.88");
+    }
+}
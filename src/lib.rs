@@ -11,4 +11,17 @@ pub use format::*;
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    #[test]
+    fn chrono_and_format_share_types() {
+        let fps: chrono::FrameRate = format::FrameRate::Fps30(true);
+        let sample_rate: chrono::SampleRate = format::SampleRate::Khz88p2;
+        let bit_depth: chrono::BitDepth = format::BitDepth::Bit24;
+
+        assert_eq!(std::any::TypeId::of::<chrono::FrameRate>(), std::any::TypeId::of::<format::FrameRate>());
+        assert_eq!(std::any::TypeId::of::<chrono::SampleRate>(), std::any::TypeId::of::<format::SampleRate>());
+        assert_eq!(std::any::TypeId::of::<chrono::BitDepth>(), std::any::TypeId::of::<format::BitDepth>());
+        assert_eq!(Timecode::with_fps(fps).frame_rate(), FrameRate::Fps30(true));
+        assert_eq!((sample_rate.as_hz(), bit_depth.bits()), (88200, 24));
+    }
 }
@@ -0,0 +1,51 @@
+// Copyright (C) Stefan Olivier
+// <https://stefanolivier.com>
+
+use timeline_kit::{
+    EDLParseTable,
+    EDLProtoolsClip,
+    EDLProtoolsError,
+    EDLProtoolsMarker,
+    EDLProtoolsMarkerLocation,
+    EDLProtoolsUnit,
+    FrameRate,
+    Timecode,
+};
+
+#[test]
+fn markers_table_parses_directly() {
+    let lines = [
+        "#   \tLOCATION     \tTIME REFERENCE    \tUNITS    \tNAME                             \tCOMMENTS",
+        "1   \t01:00:00:00  \t0                 \tSamples  \tFirst Act                        \t",
+        "2   \t01:00:06:00  \t288000            \tSamples  \tSecond Act                       \tPickup",
+    ];
+
+    let markers = EDLProtoolsMarker::parse_table(&lines, FrameRate::Fps25).expect("markers table should parse");
+
+    assert_eq!(markers.len(), 2);
+    assert_eq!(markers[1].id, 2);
+    assert_eq!(markers[1].location, EDLProtoolsMarkerLocation::Timecode(Timecode::from_str("01:00:06:00", FrameRate::Fps25).unwrap()));
+    assert_eq!(markers[1].time_reference, 288000);
+    assert_eq!(markers[1].unit, EDLProtoolsUnit::Samples);
+    assert_eq!(markers[1].name, "Second Act");
+    assert_eq!(markers[1].comment, "Pickup");
+}
+
+#[test]
+fn clips_table_parses_owned_lines() {
+    let lines = vec![
+        String::from("CLIP NAME\tSource File"),
+        String::from("Dialog_01-01\tDialog_01.wav"),
+        String::from("Malformed row"),
+    ];
+
+    let clips = EDLProtoolsClip::parse_table(&lines, ()).expect("clips table should parse");
+    assert_eq!(clips.len(), 1);
+    assert_eq!(clips[0].clip_name, "Dialog_01-01");
+    assert_eq!(clips[0].source_file, "Dialog_01.wav");
+
+    assert_eq!(
+        EDLProtoolsClip::parse_table_strict(&lines, ()),
+        Err(EDLProtoolsError::ColumnCountMismatch { line: 3, expected: 2, found: 1 }),
+    );
+}
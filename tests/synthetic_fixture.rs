@@ -0,0 +1,19 @@
+// Copyright (C) Stefan Olivier
+// <https://stefanolivier.com>
+
+#[path = "../benches/common/mod.rs"]
+mod common;
+
+use timeline_kit::EDLProtoolsParser;
+use timeline_kit::encoding;
+
+#[test]
+fn synthetic_fixture_parses() {
+    let edl = common::synthetic_edl(common::SYNTHETIC_TRACKS, common::SYNTHETIC_EVENTS_PER_TRACK, common::SYNTHETIC_MARKERS);
+    let (session, _) = EDLProtoolsParser::parse_bytes(edl.as_bytes(), encoding::UTF_8).expect("synthetic EDL should parse");
+
+    assert_eq!(session.tracks.len(), common::SYNTHETIC_TRACKS);
+    assert_eq!(session.markers.len(), common::SYNTHETIC_MARKERS);
+    assert_eq!(session.files.online_clips.len(), common::SYNTHETIC_TRACKS * common::SYNTHETIC_EVENTS_PER_TRACK);
+    assert!(session.tracks.iter().all(|track| track.events.len() == common::SYNTHETIC_EVENTS_PER_TRACK));
+}